@@ -0,0 +1,182 @@
+//! The command line of a single-day binary, `day-NN [--format text|json] [<path>]`, and the pieces
+//! its subcommands build their own from.
+
+use crate::input::Source;
+use std::str::FromStr;
+
+/// How to print the answers.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+pub enum Format {
+    /// Just the answers, one part after the other.
+    #[default]
+    Text,
+    /// One JSON object per part, each on its own line, with the day, the part, the answer, and how
+    /// long it took in microseconds.
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(format!("`{s}` is not a format; expected `text` or `json`")),
+        }
+    }
+}
+
+/// The arguments of a command that prints in one of several formats. Subcommands with formats of
+/// their own, like CSV, pick them with `F`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Args<F = Format> {
+    /// The path given, or stdin without one.
+    pub source: Source,
+    pub format: F,
+}
+
+impl<F: FromStr<Err = String> + Default> Args<F> {
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut format = F::default();
+        let source = parse_options(args, |name, value| match name {
+            "--format" => {
+                format = value.parse()?;
+                Ok(true)
+            }
+            _ => Ok(false),
+        })?;
+        Ok(Self { source, format })
+    }
+}
+
+impl Args {
+    /// The arguments the binary was run with. Prints the problem and exits if they don't make
+    /// sense.
+    pub fn from_env_or_exit() -> Self {
+        or_exit(
+            Self::parse(std::env::args().skip(1)),
+            "usage: day-NN [--format text|json] [<path>]",
+        )
+    }
+}
+
+/// Parse `--name value` (or `--name=value`) options and at most one path, handing each option to
+/// `option`, which says whether it knows it. Without a path, the input comes from stdin.
+pub fn parse_options(
+    args: impl IntoIterator<Item = String>,
+    mut option: impl FnMut(&str, &str) -> Result<bool, String>,
+) -> Result<Source, String> {
+    let mut source = None;
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        if arg.starts_with('-') && arg != "-" {
+            let known = match arg.split_once('=') {
+                Some((name, value)) => option(name, value)?,
+                None => {
+                    let value = args
+                        .next()
+                        .ok_or_else(|| format!("`{arg}` needs a value"))?;
+                    option(&arg, &value)?
+                }
+            };
+            if !known {
+                return Err(format!("unknown option `{arg}`"));
+            }
+        } else if source.is_none() {
+            source = Some(Source::from_arg(&arg));
+        } else {
+            return Err(format!("unexpected argument `{arg}`"));
+        }
+    }
+    Ok(source.unwrap_or(Source::Stdin))
+}
+
+/// The path to the input of a command with no options.
+pub fn parse_source(args: impl IntoIterator<Item = String>) -> Result<Source, String> {
+    parse_options(args, |_, _| Ok(false))
+}
+
+/// Take the next argument as a count of at least one, called `what` in errors.
+pub fn positive(args: &mut impl Iterator<Item = String>, what: &str) -> Result<usize, String> {
+    let arg = args
+        .next()
+        .ok_or_else(|| format!("the {what} is missing"))?;
+    match arg.parse() {
+        Ok(0) | Err(_) => Err(format!(
+            "`{arg}` is not a {what}; expected a positive number"
+        )),
+        Ok(count) => Ok(count),
+    }
+}
+
+/// The parsed arguments, or, if they don't make sense, exit after printing the problem and
+/// `usage`.
+pub fn or_exit<T>(args: Result<T, String>, usage: &str) -> T {
+    args.unwrap_or_else(|err| {
+        eprintln!("error: {err}\n\n{usage}");
+        std::process::exit(2);
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn strings(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    fn parse(args: &[&str]) -> Result<Args, String> {
+        Args::parse(strings(args))
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            parse(&[]),
+            Ok(Args {
+                source: Source::Stdin,
+                format: Format::Text
+            })
+        );
+        assert_eq!(
+            parse(&["--format", "json", "input.txt"]),
+            Ok(Args {
+                source: Source::File(PathBuf::from("input.txt")),
+                format: Format::Json
+            })
+        );
+        assert_eq!(
+            parse(&["-", "--format=text"]).map(|args| args.source),
+            Ok(Source::Stdin)
+        );
+        assert!(parse(&["--format", "yaml"]).is_err());
+        assert!(parse(&["--json"]).is_err());
+        assert!(parse(&["a.txt", "b.txt"]).is_err());
+        assert_eq!(
+            parse(&["--json", "input.txt"]),
+            Err(String::from("unknown option `--json`"))
+        );
+    }
+
+    #[test]
+    fn test_positive() {
+        let mut args = strings(&["3", "0", "three"]).into_iter();
+        assert_eq!(positive(&mut args, "group size"), Ok(3));
+        assert!(positive(&mut args, "group size").is_err());
+        assert_eq!(
+            positive(&mut args, "group size"),
+            Err(String::from(
+                "`three` is not a group size; expected a positive number"
+            ))
+        );
+        assert_eq!(
+            positive(&mut args, "group size"),
+            Err(String::from("the group size is missing"))
+        );
+        assert_eq!(parse_source(strings(&[])), Ok(Source::Stdin));
+        assert!(parse_source(strings(&["--format", "json"])).is_err());
+    }
+}
@@ -0,0 +1,85 @@
+//! Timing how long each step of solving a day takes, for the bench mode of the runner.
+
+use crate::Day;
+use std::time::{Duration, Instant};
+
+/// Another way of solving one of a day's parts, like the naive version that an optimized solution
+/// replaced. Bench mode times variants alongside the day's own parts, so the two can be compared.
+pub struct Variant<D: Day> {
+    /// What to call the variant in the results, like `part 1 (windows)`.
+    pub name: &'static str,
+    pub solve: fn(&D, &D::Input<'_>) -> String,
+}
+
+/// How long one step of solving a day took, over every iteration of a benchmark.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Timing {
+    step: String,
+    /// Sorted, fastest first.
+    samples: Vec<Duration>,
+}
+
+impl Timing {
+    /// Panics if there are no samples: a step that never ran has no timing.
+    pub fn new(step: impl Into<String>, mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "expected at least one sample");
+        samples.sort();
+        Self {
+            step: step.into(),
+            samples,
+        }
+    }
+
+    /// `parse`, `part 1`, `part 2`, or the name of a [`Variant`].
+    pub fn step(&self) -> &str {
+        &self.step
+    }
+
+    pub fn min(&self) -> Duration {
+        self.samples[0]
+    }
+
+    pub fn median(&self) -> Duration {
+        let middle = self.samples.len() / 2;
+        if self.samples.len().is_multiple_of(2) {
+            (self.samples[middle - 1] + self.samples[middle]) / 2
+        } else {
+            self.samples[middle]
+        }
+    }
+
+    pub fn max(&self) -> Duration {
+        self.samples[self.samples.len() - 1]
+    }
+
+    pub fn iterations(&self) -> usize {
+        self.samples.len()
+    }
+}
+
+/// Run `f`, and say how long it took. The result goes through [`std::hint::black_box`] so that
+/// the work can't be optimized away.
+pub(crate) fn time<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let value = std::hint::black_box(f());
+    (value, start.elapsed())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_timing() {
+        let ms = Duration::from_millis;
+        let timing = Timing::new("parse", vec![ms(5), ms(1), ms(3)]);
+        assert_eq!(timing.step(), "parse");
+        assert_eq!(timing.min(), ms(1));
+        assert_eq!(timing.median(), ms(3));
+        assert_eq!(timing.max(), ms(5));
+        assert_eq!(timing.iterations(), 3);
+
+        let timing = Timing::new("part 1", vec![ms(4), ms(1), ms(2), ms(8)]);
+        assert_eq!(timing.median(), ms(3));
+    }
+}
@@ -0,0 +1,208 @@
+use crate::{
+    args::{Args, Format},
+    bench::{time, Timing, Variant},
+    json::{self, ToJson},
+    random::Rng,
+    stream::StreamError,
+    ParseError,
+};
+use std::{fmt::Display, io::BufRead, time::Duration};
+
+/// A single day's puzzle: how to parse the input, and how to answer both parts from the parsed
+/// input.
+///
+/// Most days are unit structs. Days whose answers depend on more than the input, like the row that
+/// day 15 asks about, keep those settings as fields so the example and the real puzzle can both be
+/// solved.
+pub trait Day {
+    /// The day of the month this puzzle was released on.
+    const DAY: u8;
+
+    /// The parsed input. Some days borrow from the input text instead of copying it.
+    type Input<'a>;
+    type Part1: Display + ToJson;
+    type Part2: Display + ToJson;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError>;
+    fn part_1(&self, input: &Self::Input<'_>) -> Self::Part1;
+    fn part_2(&self, input: &Self::Input<'_>) -> Self::Part2;
+
+    /// Other ways of solving the parts, kept around to compare against. None by default.
+    fn variants(&self) -> Vec<Variant<Self>>
+    where
+        Self: Sized,
+    {
+        Vec::new()
+    }
+
+    /// A random puzzle input of roughly `size`, in the same format as the real one, for stress
+    /// testing. None for days without a generator.
+    fn generate(&self, _rng: &mut Rng, _size: usize) -> Option<String> {
+        None
+    }
+}
+
+/// A [`Day`] that can also be solved straight from a reader, a line at a time, so that inputs too
+/// big to hold in memory can be piped in.
+pub trait StreamingDay: Day {
+    /// Both answers for the input in `reader`, keeping as little of it in memory as the puzzle
+    /// allows.
+    fn solve_stream(&self, reader: impl BufRead)
+        -> Result<(Self::Part1, Self::Part2), StreamError>;
+}
+
+/// The answer for a part that hasn't been solved yet.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+pub struct Unsolved;
+
+impl Display for Unsolved {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("(not solved yet)")
+    }
+}
+
+/// Both answers for a day, already formatted.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Answers {
+    pub part_1: String,
+    pub part_2: String,
+}
+
+/// One part's answer, both as text and as JSON, and how long it took to work out.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Solution {
+    pub part: u8,
+    pub answer: String,
+    pub json: String,
+    pub elapsed: Duration,
+}
+
+impl Solution {
+    fn new(part: u8, answer: &(impl Display + ToJson), elapsed: Duration) -> Self {
+        Self {
+            part,
+            answer: answer.to_string(),
+            json: answer.to_json(),
+            elapsed,
+        }
+    }
+}
+
+/// A [`Day`] with its types erased, so that every day can be kept in one list and handled the same
+/// way.
+pub trait Solver {
+    fn day(&self) -> u8;
+    fn solve(&self, input: &str) -> Result<Answers, ParseError>;
+
+    /// Like [`Solver::solve`], but timing each part and keeping its answer as JSON too.
+    fn solve_timed(&self, input: &str) -> Result<[Solution; 2], ParseError>;
+
+    /// Parse and solve `input` `iterations` times (at least once), timing each step separately:
+    /// parsing, each part, and then each of the day's [variants](Day::variants).
+    fn bench(&self, input: &str, iterations: usize) -> Result<Vec<Timing>, ParseError>;
+
+    /// A random input for this day, if it has a [generator](Day::generate).
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<String>;
+}
+
+impl<D: Day> Solver for D {
+    fn day(&self) -> u8 {
+        D::DAY
+    }
+
+    fn solve(&self, input: &str) -> Result<Answers, ParseError> {
+        let parsed = self.parse(input).map_err(|err| err.locate(input))?;
+        Ok(Answers {
+            part_1: self.part_1(&parsed).to_string(),
+            part_2: self.part_2(&parsed).to_string(),
+        })
+    }
+
+    fn solve_timed(&self, input: &str) -> Result<[Solution; 2], ParseError> {
+        let parsed = self.parse(input).map_err(|err| err.locate(input))?;
+        let (part_1, elapsed_1) = time(|| self.part_1(&parsed));
+        let (part_2, elapsed_2) = time(|| self.part_2(&parsed));
+        Ok([
+            Solution::new(1, &part_1, elapsed_1),
+            Solution::new(2, &part_2, elapsed_2),
+        ])
+    }
+
+    fn bench(&self, input: &str, iterations: usize) -> Result<Vec<Timing>, ParseError> {
+        let variants = self.variants();
+        let iterations = iterations.max(1);
+        let mut parse = Vec::with_capacity(iterations);
+        let mut part_1 = Vec::with_capacity(iterations);
+        let mut part_2 = Vec::with_capacity(iterations);
+        let mut others = vec![Vec::with_capacity(iterations); variants.len()];
+        for _ in 0..iterations {
+            let (parsed, elapsed) = time(|| self.parse(input));
+            let parsed = parsed.map_err(|err| err.locate(input))?;
+            parse.push(elapsed);
+            part_1.push(time(|| self.part_1(&parsed)).1);
+            part_2.push(time(|| self.part_2(&parsed)).1);
+            for (variant, samples) in variants.iter().zip(&mut others) {
+                samples.push(time(|| (variant.solve)(self, &parsed)).1);
+            }
+        }
+
+        let mut timings = vec![
+            Timing::new("parse", parse),
+            Timing::new("part 1", part_1),
+            Timing::new("part 2", part_2),
+        ];
+        for (variant, samples) in variants.iter().zip(others) {
+            timings.push(Timing::new(variant.name, samples));
+        }
+        Ok(timings)
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<String> {
+        Day::generate(self, rng, size)
+    }
+}
+
+/// The whole `main` of a single-day binary: read the input named on the command line (or stdin),
+/// and print both answers in the format asked for.
+pub fn main<D: Day>(day: D) {
+    let args = Args::from_env_or_exit();
+    let input = crate::input::parse_or_exit(&args.source, |input| day.parse(input));
+    let (part_1, elapsed) = time(|| day.part_1(&input));
+    print_answer(args.format, D::DAY, 1, &part_1, elapsed);
+    let (part_2, elapsed) = time(|| day.part_2(&input));
+    print_answer(args.format, D::DAY, 2, &part_2, elapsed);
+}
+
+/// Like [`main`], but for a [`StreamingDay`]: the input is solved as it's read, rather than read in
+/// full first. Both parts are worked out in the same pass over the input, so in JSON both report
+/// the time the whole pass took.
+pub fn main_streaming<D: StreamingDay>(day: D) {
+    let args = Args::from_env_or_exit();
+    let reader = crate::input::open_or_exit(&args.source);
+    match time(|| day.solve_stream(reader)) {
+        (Ok((part_1, part_2)), elapsed) => {
+            print_answer(args.format, D::DAY, 1, &part_1, elapsed);
+            print_answer(args.format, D::DAY, 2, &part_2, elapsed);
+        }
+        (Err(err), _) => {
+            eprintln!("error: {err}");
+            std::process::exit(1);
+        }
+    }
+}
+
+fn print_answer(
+    format: Format,
+    day: u8,
+    part: u8,
+    answer: &(impl Display + ToJson),
+    elapsed: Duration,
+) {
+    match format {
+        Format::Text => println!("{answer}"),
+        Format::Json => println!(
+            "{}",
+            json::answer_line(day, part, &answer.to_json(), elapsed)
+        ),
+    }
+}
@@ -0,0 +1,291 @@
+use std::fmt::Display;
+
+/// The puzzle input didn't have the expected shape.
+///
+/// Parsers usually know the text they choked on, but not where it is in the whole input. So an
+/// error remembers the slice of the input it's about, and [`ParseError::locate`] later works out
+/// the line and column of that slice. That only works when the slice really is part of the input
+/// (rather than a copy of it); otherwise the error is reported without a location.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ParseError(Box<Details>);
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+struct Details {
+    message: String,
+    expected: Option<String>,
+    found: Option<String>,
+    fragment: Option<Fragment>,
+    location: Option<Location>,
+}
+
+/// Where in memory the text an error is about lives, so it can be found again in the input.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+struct Fragment {
+    start: usize,
+    len: usize,
+}
+
+impl Fragment {
+    fn of(text: &str) -> Self {
+        Self {
+            start: text.as_ptr() as usize,
+            len: text.len(),
+        }
+    }
+
+    /// Where this fragment starts in `text`, if it lies within it.
+    fn offset_in(self, text: &str) -> Option<usize> {
+        let start = text.as_ptr() as usize;
+        let offset = self.start.checked_sub(start)?;
+        (offset + self.len <= text.len()).then_some(offset)
+    }
+}
+
+/// Where in the puzzle input a [`ParseError`] happened.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Location {
+    line: usize,
+    column: usize,
+    width: usize,
+    text: String,
+}
+
+impl Location {
+    /// The line number, starting from one.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// The column, in characters, starting from one.
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    /// The whole line the error is on.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+}
+
+impl ParseError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self(Box::new(Details {
+            message: message.into(),
+            expected: None,
+            found: None,
+            fragment: None,
+            location: None,
+        }))
+    }
+
+    /// The parser wanted `expected`, but the input had `found` instead. `found` should be a slice of
+    /// the input so that the error can be located.
+    pub fn expected(expected: impl Into<String>, found: &str) -> Self {
+        let expected = expected.into();
+        let message = if found.is_empty() {
+            format!("expected {expected}, found nothing")
+        } else {
+            format!("expected {expected}, found `{found}`")
+        };
+        Self(Box::new(Details {
+            message,
+            expected: Some(expected),
+            found: Some(found.to_string()),
+            fragment: Some(Fragment::of(found)),
+            location: None,
+        }))
+    }
+
+    /// The parser wanted `expected` where `rest` (the unparsed remainder of the input) starts. Only
+    /// the first character of `rest` is reported as found, which suits parsers that stop partway
+    /// through a line.
+    pub fn expected_at(expected: impl Into<String>, rest: &str) -> Self {
+        let len = rest.chars().next().map_or(0, char::len_utf8);
+        Self::expected(expected, &rest[..len])
+    }
+
+    /// Point the error at `text` (a slice of the input), unless it already points somewhere inside
+    /// of it. Parsers that handle one piece of the input at a time use this so that errors from
+    /// further down still say which piece they came from.
+    pub fn within(mut self, text: &str) -> Self {
+        let inside = self
+            .0
+            .fragment
+            .is_some_and(|fragment| fragment.offset_in(text).is_some());
+        if !inside {
+            self.0.fragment = Some(Fragment::of(text));
+        }
+        self
+    }
+
+    /// Work out which line and column of `input` the error is about.
+    pub fn locate(mut self, input: &str) -> Self {
+        if self.0.location.is_some() {
+            return self;
+        }
+        let Some(fragment) = self.0.fragment else {
+            return self;
+        };
+        let Some(offset) = fragment.offset_in(input) else {
+            return self;
+        };
+
+        let line_start = input[..offset].rfind('\n').map_or(0, |index| index + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |index| offset + index);
+        let text = input[line_start..line_end].trim_end_matches('\r');
+        let fragment_end = std::cmp::min(offset + fragment.len, line_start + text.len());
+        let width = input
+            .get(offset..fragment_end)
+            .map_or(0, |fragment| fragment.chars().count());
+
+        self.0.location = Some(Location {
+            line: input[..offset].matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            width: std::cmp::max(width, 1),
+            text: text.to_string(),
+        });
+        self
+    }
+
+    /// Like [`ParseError::locate`], for input read a line at a time: `line` is the text of line
+    /// number `line_number` (counting from one) of the whole input.
+    pub fn locate_line(self, line: &str, line_number: usize) -> Self {
+        let mut err = self.locate(line);
+        if let Some(location) = &mut err.0.location {
+            location.line = line_number;
+        }
+        err
+    }
+
+    /// What went wrong, in one line and without the location.
+    pub fn message(&self) -> &str {
+        &self.0.message
+    }
+
+    /// What the parser wanted, if it said.
+    pub fn expected_text(&self) -> Option<&str> {
+        self.0.expected.as_deref()
+    }
+
+    /// What the parser found instead, if it said.
+    pub fn found_text(&self) -> Option<&str> {
+        self.0.found.as_deref()
+    }
+
+    /// Where the error is, once it has been [located](ParseError::locate).
+    pub fn location(&self) -> Option<&Location> {
+        self.0.location.as_ref()
+    }
+}
+
+impl From<&str> for ParseError {
+    fn from(message: &str) -> Self {
+        Self::new(message)
+    }
+}
+
+impl From<String> for ParseError {
+    fn from(message: String) -> Self {
+        Self::new(message)
+    }
+}
+
+/// Without a location, this is just the message. With one, it also shows the line and points at
+/// the offending text:
+///
+/// ```text
+/// line 3, column 6: expected a number, found `x`
+///   |
+/// 3 | move x from 1 to 2
+///   |      ^
+/// ```
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Some(location) = &self.0.location else {
+            return f.write_str(&self.0.message);
+        };
+        let line = location.line.to_string();
+        let gutter = " ".repeat(line.len());
+        writeln!(
+            f,
+            "line {}, column {}: {}",
+            location.line, location.column, self.0.message
+        )?;
+        writeln!(f, "{gutter} |")?;
+        writeln!(f, "{line} | {}", location.text)?;
+        write!(
+            f,
+            "{gutter} | {}{}",
+            " ".repeat(location.column - 1),
+            "^".repeat(location.width)
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parse each line of `input` with `f`, pointing any error that doesn't already know where it
+/// happened at the line it came from.
+pub fn parse_lines<'a, T, E: Into<ParseError>>(
+    input: &'a str,
+    mut f: impl FnMut(&'a str) -> Result<T, E>,
+) -> Result<Vec<T>, ParseError> {
+    input
+        .lines()
+        .map(|line| f(line).map_err(|err| err.into().within(line)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "move 1 from 2 to 1\nmove x from 1 to 2\nmove 2 from 2 to 1\n";
+
+    #[test]
+    fn test_locate() {
+        let line = INPUT.lines().nth(1).unwrap();
+        let err = ParseError::expected("a number", &line[5..6]).locate(INPUT);
+        let location = err.location().unwrap();
+        assert_eq!(location.line(), 2);
+        assert_eq!(location.column(), 6);
+        assert_eq!(location.text(), "move x from 1 to 2");
+        assert_eq!(err.message(), "expected a number, found `x`");
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 6: expected a number, found `x`\n  |\n2 | move x from 1 to 2\n  |      ^"
+        );
+    }
+
+    #[test]
+    fn test_not_in_input() {
+        let copy = String::from("x");
+        let err = ParseError::expected("a number", &copy).locate(INPUT);
+        assert_eq!(err.location(), None);
+        assert_eq!(err.to_string(), "expected a number, found `x`");
+    }
+
+    #[test]
+    fn test_within() {
+        let err = parse_lines(INPUT, |line| {
+            let count = line.split(' ').nth(1).unwrap();
+            count
+                .parse::<u8>()
+                .map_err(|_| ParseError::new("bad count"))
+        })
+        .unwrap_err()
+        .locate(INPUT);
+        let location = err.location().unwrap();
+        assert_eq!((location.line(), location.column()), (2, 1));
+        assert!(err.to_string().ends_with("  | ^^^^^^^^^^^^^^^^^^"));
+
+        // An error that already points inside the line keeps pointing there.
+        let line = INPUT.lines().nth(2).unwrap();
+        let err = ParseError::expected("a move", &line[5..6])
+            .within(line)
+            .locate(INPUT);
+        assert_eq!(err.location().unwrap().column(), 6);
+    }
+}
@@ -0,0 +1,31 @@
+/// Declare tests that solve a puzzle example through [`Day`](crate::Day) and check one part's
+/// answer against the one from the puzzle text.
+///
+/// Each line names the test, the day (any expression, so days with settings can set them for the
+/// example), the example file next to the calling source file, the part, and the expected answer.
+/// Answers are compared by how they print, so pictures can be checked against a string.
+///
+/// ```ignore
+/// aoc_core::example_tests! {
+///     test_part_1: Day15 { row: 10, size: 20 }, "example.txt", part_1 => 26;
+///     test_part_2: Day15 { row: 10, size: 20 }, "example.txt", part_2 => 56_000_011;
+/// }
+/// ```
+#[macro_export]
+macro_rules! example_tests {
+    ($($name:ident: $day:expr, $file:literal, $part:ident => $expected:expr;)+) => {
+        $(
+            #[test]
+            fn $name() {
+                use $crate::Day as _;
+                let day = $day;
+                let input = include_str!($file);
+                let parsed = match day.parse(input) {
+                    Ok(parsed) => parsed,
+                    Err(err) => panic!("failed to parse {}: {}", $file, err.locate(input)),
+                };
+                assert_eq!(day.$part(&parsed).to_string(), $expected.to_string());
+            }
+        )+
+    };
+}
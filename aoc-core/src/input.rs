@@ -0,0 +1,164 @@
+//! Reading puzzle input at runtime, from a file or from stdin.
+
+use crate::ParseError;
+use std::{
+    fmt::Display,
+    io::{BufRead, BufReader, IsTerminal, Read},
+    path::{Path, PathBuf},
+};
+
+/// Where to read the puzzle input from.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Source {
+    Stdin,
+    File(PathBuf),
+}
+
+impl Source {
+    /// A path of `-` means stdin, like most command line tools.
+    pub fn from_arg(arg: &str) -> Self {
+        if arg == "-" {
+            Self::Stdin
+        } else {
+            Self::File(PathBuf::from(arg))
+        }
+    }
+
+    /// Where this workspace keeps the input for `day`: `day-NN/src/input.txt` under `root`.
+    pub fn for_day(root: &Path, day: u8) -> Self {
+        Self::File(
+            root.join(format!("day-{day:02}"))
+                .join("src")
+                .join("input.txt"),
+        )
+    }
+
+    pub fn read(&self) -> Result<String, InputError> {
+        match self {
+            Source::Stdin => read_stdin(),
+            Source::File(path) => read_file(path),
+        }
+    }
+
+    /// Open the input for reading a bit at a time, rather than all at once.
+    pub fn open(&self) -> Result<Box<dyn BufRead>, InputError> {
+        match self {
+            Source::Stdin => {
+                let stdin = std::io::stdin();
+                if stdin.is_terminal() {
+                    return Err(InputError::NoInput);
+                }
+                Ok(Box::new(stdin.lock()))
+            }
+            Source::File(path) => match std::fs::File::open(path) {
+                Ok(file) => Ok(Box::new(BufReader::new(file))),
+                Err(err) => Err(InputError::File(path.to_path_buf(), err)),
+            },
+        }
+    }
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Source::Stdin => f.write_str("<stdin>"),
+            Source::File(path) => path.display().fmt(f),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    /// Nothing was piped in and no file was given, so there is nothing to read.
+    NoInput,
+    Stdin(std::io::Error),
+    File(PathBuf, std::io::Error),
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::NoInput => f.write_str(
+                "no puzzle input: pass the path to an input file, or pipe the input on stdin",
+            ),
+            InputError::Stdin(err) => write!(f, "could not read puzzle input from stdin: {err}"),
+            InputError::File(path, err) if err.kind() == std::io::ErrorKind::NotFound => {
+                write!(f, "puzzle input file `{}` does not exist", path.display())
+            }
+            InputError::File(path, err) => {
+                write!(
+                    f,
+                    "could not read puzzle input file `{}`: {err}",
+                    path.display()
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InputError::NoInput => None,
+            InputError::Stdin(err) => Some(err),
+            InputError::File(_, err) => Some(err),
+        }
+    }
+}
+
+pub fn read_file(path: &Path) -> Result<String, InputError> {
+    std::fs::read_to_string(path).map_err(|err| InputError::File(path.to_path_buf(), err))
+}
+
+pub fn read_stdin() -> Result<String, InputError> {
+    let mut stdin = std::io::stdin();
+    if stdin.is_terminal() {
+        // Nobody is going to type a few thousand lines of puzzle input by hand. Waiting here would
+        // just look like a hang.
+        return Err(InputError::NoInput);
+    }
+    let mut string = String::new();
+    stdin
+        .read_to_string(&mut string)
+        .map_err(InputError::Stdin)?;
+    Ok(string)
+}
+
+/// Read the puzzle input for a single-day binary.
+///
+/// Prints the problem and exits when the input can't be read, since there's nothing useful a
+/// binary can do without its input.
+pub fn read_or_exit(source: &Source) -> String {
+    exit_on_error(source.read())
+}
+
+/// Read the puzzle input and `parse` it, for a binary that can't go on without it.
+///
+/// Prints where the input went wrong and exits when it doesn't parse. The input is kept for the
+/// rest of the program, so the parsed value may borrow from it.
+pub fn parse_or_exit<T>(
+    source: &Source,
+    parse: impl FnOnce(&'static str) -> Result<T, ParseError>,
+) -> T {
+    let input: &'static str = read_or_exit(source).leak();
+    parse(input).unwrap_or_else(|err| {
+        let err = err.locate(input);
+        eprintln!("error: failed to parse the puzzle input: {err}");
+        std::process::exit(1);
+    })
+}
+
+/// Like [`read_or_exit`], but opening the input to be read a line at a time.
+pub fn open_or_exit(source: &Source) -> Box<dyn BufRead> {
+    exit_on_error(source.open())
+}
+
+fn exit_on_error<T>(result: Result<T, InputError>) -> T {
+    match result {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("error: {err}");
+            std::process::exit(1);
+        }
+    }
+}
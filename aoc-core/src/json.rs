@@ -0,0 +1,133 @@
+//! Just enough JSON writing for machine-readable output: answers, and the runner's results.
+
+use crate::Unsolved;
+use std::{fmt::Write, time::Duration};
+
+/// An answer that can be written as JSON. Numbers and strings are written as themselves, and
+/// answers with more shape to them, like the picture on day 10's CRT, keep it.
+pub trait ToJson {
+    fn to_json(&self) -> String;
+}
+
+macro_rules! impl_to_json_for_numbers {
+    ($($ty:ty),*) => {
+        $(
+            impl ToJson for $ty {
+                fn to_json(&self) -> String {
+                    self.to_string()
+                }
+            }
+        )*
+    };
+}
+
+impl_to_json_for_numbers!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl ToJson for bool {
+    fn to_json(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl ToJson for str {
+    fn to_json(&self) -> String {
+        string(self)
+    }
+}
+
+impl ToJson for String {
+    fn to_json(&self) -> String {
+        string(self)
+    }
+}
+
+impl<T: ToJson> ToJson for [T] {
+    fn to_json(&self) -> String {
+        array(self.iter().map(ToJson::to_json))
+    }
+}
+
+impl<T: ToJson> ToJson for Vec<T> {
+    fn to_json(&self) -> String {
+        self.as_slice().to_json()
+    }
+}
+
+/// There's no answer yet, so there's nothing to write.
+impl ToJson for Unsolved {
+    fn to_json(&self) -> String {
+        String::from("null")
+    }
+}
+
+/// `value` as a quoted JSON string.
+pub fn string(value: &str) -> String {
+    let mut json = String::with_capacity(value.len() + 2);
+    json.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if c.is_control() => write!(json, "\\u{:04x}", c as u32).unwrap(),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+/// A JSON array of values that are already JSON.
+pub fn array(items: impl IntoIterator<Item = String>) -> String {
+    let mut json = String::from("[");
+    for (index, item) in items.into_iter().enumerate() {
+        if index > 0 {
+            json.push(',');
+        }
+        json.push_str(&item);
+    }
+    json.push(']');
+    json
+}
+
+/// One line of output for one part of a day, like
+/// `{"day":10,"part":1,"answer":13140,"elapsed_us":12}`. `answer` is already JSON.
+pub fn answer_line(day: u8, part: u8, answer: &str, elapsed: Duration) -> String {
+    format!(
+        "{{\"day\":{day},\"part\":{part},\"answer\":{answer},\"elapsed_us\":{}}}",
+        elapsed.as_micros()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_string() {
+        assert_eq!(string("part 1"), r#""part 1""#);
+        assert_eq!(string("say \"hi\"\n"), r#""say \"hi\"\n""#);
+        assert_eq!(string("a\\b\u{1}"), r#""a\\b\u0001""#);
+    }
+
+    #[test]
+    fn test_to_json() {
+        assert_eq!((-42i64).to_json(), "-42");
+        assert_eq!(String::from("CMZ").to_json(), r#""CMZ""#);
+        assert_eq!(
+            vec![vec![true, false], vec![]].to_json(),
+            "[[true,false],[]]"
+        );
+        assert_eq!(Unsolved.to_json(), "null");
+    }
+
+    #[test]
+    fn test_answer_line() {
+        assert_eq!(
+            answer_line(5, 2, &"MCD".to_json(), Duration::from_nanos(12_345)),
+            r#"{"day":5,"part":2,"answer":"MCD","elapsed_us":12}"#
+        );
+    }
+}
@@ -0,0 +1,15 @@
+//! Pieces shared by every day of the puzzle: reading the puzzle input at runtime and the common
+//! shape of a solution.
+
+pub mod args;
+pub mod bench;
+mod day;
+mod error;
+mod example;
+pub mod input;
+pub mod json;
+pub mod random;
+pub mod stream;
+
+pub use day::{main, main_streaming, Answers, Day, Solution, Solver, StreamingDay, Unsolved};
+pub use error::{parse_lines, Location, ParseError};
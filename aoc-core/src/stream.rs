@@ -0,0 +1,132 @@
+//! Parsing puzzle input straight from a reader, a line at a time, for inputs too big to read into
+//! one string first.
+
+use crate::ParseError;
+use std::{fmt::Display, io::BufRead};
+
+/// Reading a streamed input failed, either because the reader did or because a line didn't parse.
+#[derive(Debug)]
+pub enum StreamError {
+    Io(std::io::Error),
+    Parse(ParseError),
+}
+
+impl Display for StreamError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StreamError::Io(err) => write!(f, "could not read puzzle input: {err}"),
+            StreamError::Parse(err) => write!(f, "failed to parse the puzzle input: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for StreamError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            StreamError::Io(err) => Some(err),
+            StreamError::Parse(err) => Some(err),
+        }
+    }
+}
+
+impl From<ParseError> for StreamError {
+    fn from(err: ParseError) -> Self {
+        StreamError::Parse(err)
+    }
+}
+
+/// Like [`crate::parse_lines`], but reading from `reader` and parsing each line as it arrives.
+/// Lines are read into the same buffer, so only the longest line is ever held in memory. Errors
+/// are located at the line they came from.
+///
+/// The iterator ends after the first error.
+pub fn parse_lines<R, T, E, F>(reader: R, f: F) -> ParseLines<R, F>
+where
+    R: BufRead,
+    E: Into<ParseError>,
+    F: FnMut(&str) -> Result<T, E>,
+{
+    ParseLines {
+        reader,
+        f,
+        buffer: String::new(),
+        line_number: 0,
+        failed: false,
+    }
+}
+
+/// The iterator returned by [`parse_lines`].
+pub struct ParseLines<R, F> {
+    reader: R,
+    f: F,
+    buffer: String,
+    line_number: usize,
+    failed: bool,
+}
+
+impl<R, T, E, F> Iterator for ParseLines<R, F>
+where
+    R: BufRead,
+    E: Into<ParseError>,
+    F: FnMut(&str) -> Result<T, E>,
+{
+    type Item = Result<T, StreamError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+        self.buffer.clear();
+        match self.reader.read_line(&mut self.buffer) {
+            Ok(0) => return None,
+            Ok(_) => {}
+            Err(err) => {
+                self.failed = true;
+                return Some(Err(StreamError::Io(err)));
+            }
+        }
+        self.line_number += 1;
+        let line = self.buffer.strip_suffix('\n').unwrap_or(&self.buffer);
+        let line = line.strip_suffix('\r').unwrap_or(line);
+        let parsed = (self.f)(line).map_err(|err| {
+            self.failed = true;
+            let err = err.into().within(line);
+            StreamError::Parse(err.locate_line(line, self.line_number))
+        });
+        Some(parsed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_lines() {
+        let input = "1\r\n2\n\n3";
+        let numbers = parse_lines(input.as_bytes(), |line| {
+            line.parse::<u8>().or(Ok::<_, ParseError>(0))
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+        assert_eq!(numbers, [1, 2, 0, 3]);
+    }
+
+    #[test]
+    fn test_error_line() {
+        let input = "1\n2\nx\n4\n";
+        let mut lines = parse_lines(input.as_bytes(), |line| {
+            line.parse::<u8>()
+                .map_err(|_| ParseError::expected("a number", line))
+        });
+        assert!(matches!(lines.next(), Some(Ok(1))));
+        assert!(matches!(lines.next(), Some(Ok(2))));
+        let Some(Err(StreamError::Parse(err))) = lines.next() else {
+            panic!("expected a parse error");
+        };
+        let location = err.location().unwrap();
+        assert_eq!((location.line(), location.column()), (3, 1));
+        assert_eq!(location.text(), "x");
+        assert!(lines.next().is_none());
+    }
+}
@@ -0,0 +1,244 @@
+//! `aoc all`: solve every day's real input at once, a day per thread, and sum up how each went.
+
+use crate::{bench::format_duration, cli::All, days, panics};
+use aoc_core::{args::Format, input::Source, json, Solution, Solver};
+use std::{
+    fmt::Write,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+/// How solving one day went.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Outcome {
+    Solved([Solution; 2]),
+    /// The input couldn't be read or parsed.
+    Error(String),
+    Panicked(String),
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct DayResult {
+    pub day: u8,
+    pub outcome: Outcome,
+    /// From starting to read the input to having both answers.
+    pub elapsed: Duration,
+}
+
+/// Solve every day, print the results in day order, and say whether they were all solved.
+pub fn run(all: &All) -> Result<bool, Box<dyn std::error::Error>> {
+    let mut days = days::select(&all.days)?
+        .iter()
+        .map(|solver| solver.day())
+        .collect::<Vec<_>>();
+    days.sort();
+    days.dedup();
+
+    let threads = all
+        .threads
+        .or_else(|| thread::available_parallelism().ok().map(usize::from))
+        .unwrap_or(1);
+    let results = parallel_map(&days, threads, |&day| {
+        // Solvers aren't shared between threads, so each day gets its own.
+        let solver = days::find(day).expect("the day was found before");
+        solve_day(&*solver, &all.source(day))
+    });
+
+    match all.format {
+        Format::Text => print!("{}", summary(&results)),
+        Format::Json => {
+            for line in json_lines(&results) {
+                println!("{line}");
+            }
+        }
+    }
+    Ok(results
+        .iter()
+        .all(|result| matches!(result.outcome, Outcome::Solved(_))))
+}
+
+/// `f` applied to every item, on up to `threads` threads at once, with the results in the same
+/// order as the items. Each thread takes the next item as soon as it's done with the last, so one
+/// slow item doesn't hold up the rest.
+pub fn parallel_map<T: Sync, R: Send>(
+    items: &[T],
+    threads: usize,
+    f: impl Fn(&T) -> R + Sync,
+) -> Vec<R> {
+    let next = AtomicUsize::new(0);
+    let results = Mutex::new((0..items.len()).map(|_| None).collect::<Vec<_>>());
+    thread::scope(|scope| {
+        for _ in 0..threads.clamp(1, items.len().max(1)) {
+            scope.spawn(|| loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                let Some(item) = items.get(index) else {
+                    break;
+                };
+                let result = f(item);
+                results.lock().unwrap()[index] = Some(result);
+            });
+        }
+    });
+    results
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .map(|result| result.expect("every item was mapped"))
+        .collect()
+}
+
+/// Read and solve one day's input, catching any panic.
+pub fn solve_day(solver: &dyn Solver, source: &Source) -> DayResult {
+    let day = solver.day();
+    let start = Instant::now();
+    let outcome = match source.read() {
+        Err(err) => Outcome::Error(err.to_string()),
+        Ok(input) => match panics::catch(|| solver.solve_timed(&input)) {
+            Ok(Ok(solutions)) => Outcome::Solved(solutions),
+            Ok(Err(err)) => Outcome::Error(format!("failed to parse the puzzle input: {err}")),
+            Err(message) => Outcome::Panicked(message),
+        },
+    };
+    DayResult {
+        day,
+        outcome,
+        elapsed: start.elapsed(),
+    }
+}
+
+/// A table with a row per day, followed by anything too long for it: what went wrong, and answers
+/// that are pictures.
+fn summary(results: &[DayResult]) -> String {
+    let mut table = format!(
+        "{:>3}  {:<8} {:>8}  {:<20} {}\n",
+        "day", "result", "time", "part 1", "part 2"
+    );
+    let mut details = String::new();
+    for result in results {
+        let day = result.day;
+        let elapsed = format_duration(result.elapsed);
+        let (status, part_1, part_2) = match &result.outcome {
+            Outcome::Solved([part_1, part_2]) => {
+                let mut cell = |solution: &Solution| {
+                    if !solution.answer.contains('\n') {
+                        return solution.answer.clone();
+                    }
+                    let picture = solution.answer.trim_end();
+                    writeln!(details, "day {day} part {}:\n{picture}", solution.part).unwrap();
+                    String::from("(below)")
+                };
+                ("ok", cell(part_1), cell(part_2))
+            }
+            Outcome::Error(err) => {
+                writeln!(details, "day {day}: {err}").unwrap();
+                ("error", String::new(), String::new())
+            }
+            Outcome::Panicked(message) => {
+                writeln!(details, "day {day}: panicked: {message}").unwrap();
+                ("panicked", String::new(), String::new())
+            }
+        };
+        writeln!(
+            table,
+            "{day:>3}  {status:<8} {elapsed:>8}  {part_1:<20} {part_2}"
+        )
+        .unwrap();
+    }
+    if !details.is_empty() {
+        write!(table, "\n{details}").unwrap();
+    }
+    table
+}
+
+/// A line per part for the days that were solved, like `aoc run --format json`, and a line with
+/// the error for each day that wasn't.
+fn json_lines(results: &[DayResult]) -> Vec<String> {
+    let mut lines = Vec::new();
+    for result in results {
+        let day = result.day;
+        match &result.outcome {
+            Outcome::Solved(solutions) => {
+                for solution in solutions {
+                    let line =
+                        json::answer_line(day, solution.part, &solution.json, solution.elapsed);
+                    lines.push(line);
+                }
+            }
+            Outcome::Error(err) => {
+                lines.push(format!("{{\"day\":{day},\"error\":{}}}", json::string(err)));
+            }
+            Outcome::Panicked(message) => {
+                let err = format!("panicked: {message}");
+                lines.push(format!(
+                    "{{\"day\":{day},\"error\":{}}}",
+                    json::string(&err)
+                ));
+            }
+        }
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::{Day, ParseError};
+    use std::path::PathBuf;
+
+    /// Gives up on any input that isn't a small number.
+    struct Picky;
+
+    impl Day for Picky {
+        const DAY: u8 = 21;
+        type Input<'a> = &'a str;
+        type Part1 = u8;
+        type Part2 = u8;
+
+        fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+            Ok(input.trim())
+        }
+
+        fn part_1(&self, input: &Self::Input<'_>) -> Self::Part1 {
+            input.parse().unwrap_or_else(|_| todo!())
+        }
+
+        fn part_2(&self, _input: &Self::Input<'_>) -> Self::Part2 {
+            0
+        }
+    }
+
+    #[test]
+    fn test_parallel_map() {
+        let items = (0..50).collect::<Vec<u64>>();
+        let squares = parallel_map(&items, 4, |item| item * item);
+        assert_eq!(
+            squares,
+            items.iter().map(|item| item * item).collect::<Vec<_>>()
+        );
+        assert!(parallel_map(&[] as &[u8], 4, |_| ()).is_empty());
+    }
+
+    #[test]
+    fn test_solve_day() {
+        let path = std::env::temp_dir().join(format!("aoc-all-{}.txt", std::process::id()));
+        std::fs::write(&path, "many\n").unwrap();
+        let result = solve_day(&Picky, &Source::File(path.clone()));
+        std::fs::remove_file(&path).unwrap();
+        let Outcome::Panicked(message) = &result.outcome else {
+            panic!("expected a panic, got {:?}", result.outcome);
+        };
+        assert!(message.starts_with("not yet implemented at "), "{message}");
+
+        let missing = Source::File(PathBuf::from("does/not/exist.txt"));
+        let result = solve_day(&Picky, &missing);
+        assert!(matches!(result.outcome, Outcome::Error(_)));
+
+        let summary = summary(&[result]);
+        assert!(summary.contains(" 21  error"), "{summary}");
+        assert!(summary.contains("\nday 21: puzzle input file"), "{summary}");
+    }
+}
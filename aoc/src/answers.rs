@@ -0,0 +1,125 @@
+//! The registry of known answers that `aoc verify` checks the solutions against.
+//!
+//! The file has an entry for each day and part that has a known answer:
+//!
+//! ```text
+//! # Comments and blank lines are ignored.
+//! day 1 part 1: 69177
+//! day 10 part 2:
+//!     ###..####.
+//!     #..#....#.
+//! ```
+//!
+//! Answers that are pictures, like the CRT on day 10, start on the next line and are indented by
+//! four spaces.
+
+use aoc_core::ParseError;
+use std::collections::BTreeMap;
+
+const INDENT: &str = "    ";
+
+#[derive(Debug, Default, Eq, PartialEq)]
+pub struct Registry(BTreeMap<(u8, u8), String>);
+
+impl Registry {
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        self.0.get(&(day, part)).map(String::as_str)
+    }
+
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        Self::parse_entries(input).map_err(|err| err.locate(input))
+    }
+
+    fn parse_entries(input: &str) -> Result<Self, ParseError> {
+        let mut answers = BTreeMap::new();
+        // The entry that indented lines belong to, while it's still open to them.
+        let mut picture: Option<(u8, u8)> = None;
+        for line in input.lines() {
+            if let Some(row) = line.strip_prefix(INDENT) {
+                let key = picture.ok_or_else(|| {
+                    ParseError::new("expected a `day N part P:` line before a picture").within(line)
+                })?;
+                let answer: &mut String = answers.entry(key).or_default();
+                if !answer.is_empty() {
+                    answer.push('\n');
+                }
+                answer.push_str(row.trim_end());
+                continue;
+            }
+            picture = None;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (key, answer) = line
+                .split_once(':')
+                .ok_or_else(|| ParseError::expected("`day N part P: <answer>`", line))?;
+            let key = parse_key(key)?;
+            if answers.contains_key(&key) {
+                return Err(ParseError::new(format!(
+                    "day {} part {} has more than one answer",
+                    key.0, key.1
+                ))
+                .within(line));
+            }
+            let answer = answer.trim();
+            if answer.is_empty() {
+                picture = Some(key);
+            }
+            answers.insert(key, answer.to_string());
+        }
+        Ok(Self(answers))
+    }
+}
+
+/// `day 1 part 2`, as `(1, 2)`.
+fn parse_key(key: &str) -> Result<(u8, u8), ParseError> {
+    let expected = || ParseError::expected("`day N part P`", key);
+    let rest = key.strip_prefix("day ").ok_or_else(expected)?;
+    let (day, part) = rest.split_once(" part ").ok_or_else(expected)?;
+    let day = day
+        .parse()
+        .map_err(|_| ParseError::expected("a day number", day))?;
+    let part = match part {
+        "1" => 1,
+        "2" => 2,
+        _ => return Err(ParseError::expected("part `1` or `2`", part)),
+    };
+    Ok((day, part))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let registry = Registry::parse(
+            "# Checked by hand\n\
+             day 1 part 1: 24000\n\
+             \n\
+             day 10 part 2:\n    \
+             ##..\n    \
+             #...\n\
+             day 10 part 1: 13140\n",
+        )
+        .unwrap();
+        assert_eq!(registry.get(1, 1), Some("24000"));
+        assert_eq!(registry.get(1, 2), None);
+        assert_eq!(registry.get(10, 1), Some("13140"));
+        assert_eq!(registry.get(10, 2), Some("##..\n#..."));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let input = "day 1 part 1: 1\nday 1 part 3: 2\n";
+        let err = Registry::parse(input).unwrap_err();
+        let location = err.location().unwrap();
+        assert_eq!((location.line(), location.column()), (2, 12));
+
+        assert!(Registry::parse("day 1 part 1: 1\nday 1 part 1: 2\n").is_err());
+        assert!(Registry::parse("day 1 part 1: 1\n    ##\n").is_err());
+        assert!(Registry::parse("1 1 24000\n").is_err());
+    }
+}
@@ -0,0 +1,148 @@
+//! `aoc bench`: time parsing and solving each day, and report how long every step took.
+
+use crate::{cli::Bench, days};
+use aoc_core::{bench::Timing, json};
+use std::{fmt::Write, time::Duration};
+
+/// The timings for every step of one day.
+pub struct DayTimings {
+    pub day: u8,
+    pub timings: Vec<Timing>,
+}
+
+pub fn run(bench: &Bench) -> Result<(), Box<dyn std::error::Error>> {
+    let solvers = days::select(&bench.days)?;
+
+    println!(
+        "{:>3}  {:<24} {:>8} {:>11} {:>11}",
+        "day", "step", "min", "median", "max"
+    );
+    let mut results = Vec::with_capacity(solvers.len());
+    for solver in solvers {
+        let day = solver.day();
+        let input = bench.source(day).read()?;
+        let timings = solver
+            .bench(&input, bench.iterations)
+            .map_err(|err| format!("failed to parse the puzzle input for day {day}: {err}"))?;
+        let result = DayTimings { day, timings };
+        // Print each day as soon as it's done, since the slow days take a while.
+        print!("{}", rows(&result));
+        results.push(result);
+    }
+
+    if let Some(path) = &bench.json {
+        std::fs::write(path, to_json(bench.iterations, &results))
+            .map_err(|err| format!("could not write `{}`: {err}", path.display()))?;
+    }
+    Ok(())
+}
+
+/// One line of the table per step, with the day only on the first.
+fn rows(result: &DayTimings) -> String {
+    let mut rows = String::new();
+    for (index, timing) in result.timings.iter().enumerate() {
+        let day = if index == 0 {
+            result.day.to_string()
+        } else {
+            String::new()
+        };
+        writeln!(
+            rows,
+            "{day:>3}  {:<24} {:>8} {:>11} {:>11}",
+            timing.step(),
+            format_duration(timing.min()),
+            format_duration(timing.median()),
+            format_duration(timing.max()),
+        )
+        .unwrap();
+    }
+    rows
+}
+
+/// Durations rounded to a readable unit, like `12.3ms`.
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+    if nanos < 1_000 {
+        format!("{nanos}ns")
+    } else if nanos < 1_000_000 {
+        format!("{:.1}µs", nanos as f64 / 1e3)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.1}ms", nanos as f64 / 1e6)
+    } else {
+        format!("{:.2}s", duration.as_secs_f64())
+    }
+}
+
+/// The results as JSON, with every time in nanoseconds.
+fn to_json(iterations: usize, results: &[DayTimings]) -> String {
+    let mut out = format!("{{\n  \"iterations\": {iterations},\n  \"days\": [");
+    for (index, result) in results.iter().enumerate() {
+        let separator = if index == 0 { "" } else { "," };
+        write!(
+            out,
+            "{separator}\n    {{\"day\": {}, \"steps\": [",
+            result.day
+        )
+        .unwrap();
+        for (index, timing) in result.timings.iter().enumerate() {
+            let separator = if index == 0 { "" } else { ", " };
+            write!(
+                out,
+                "{separator}{{\"step\": {}, \"min_ns\": {}, \"median_ns\": {}, \"max_ns\": {}}}",
+                json::string(timing.step()),
+                timing.min().as_nanos(),
+                timing.median().as_nanos(),
+                timing.max().as_nanos(),
+            )
+            .unwrap();
+        }
+        out.push_str("]}");
+    }
+    out.push_str("\n  ]\n}\n");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> DayTimings {
+        let micros = Duration::from_micros;
+        DayTimings {
+            day: 6,
+            timings: vec![
+                Timing::new("parse", vec![Duration::from_nanos(300)]),
+                Timing::new("part 1", vec![micros(12), micros(15), micros(20)]),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_nanos(999)), "999ns");
+        assert_eq!(format_duration(Duration::from_nanos(12_345)), "12.3µs");
+        assert_eq!(format_duration(Duration::from_micros(4_560)), "4.6ms");
+        assert_eq!(format_duration(Duration::from_millis(2_500)), "2.50s");
+    }
+
+    #[test]
+    fn test_rows() {
+        assert_eq!(
+            rows(&example()),
+            "  6  parse                       300ns       300ns       300ns\n     \
+             part 1                     12.0µs      15.0µs      20.0µs\n"
+        );
+    }
+
+    #[test]
+    fn test_json() {
+        assert_eq!(
+            to_json(3, &[example()]),
+            "{\n  \"iterations\": 3,\n  \"days\": [\n    \
+             {\"day\": 6, \"steps\": [\
+             {\"step\": \"parse\", \"min_ns\": 300, \"median_ns\": 300, \"max_ns\": 300}, \
+             {\"step\": \"part 1\", \"min_ns\": 12000, \"median_ns\": 15000, \"max_ns\": 20000}\
+             ]}\n  ]\n}\n"
+        );
+    }
+}
@@ -0,0 +1,577 @@
+use aoc_core::{args::Format, input::Source};
+use std::{fmt::Display, path::PathBuf};
+
+pub const USAGE: &str = "\
+usage: aoc run <day> [--input <path>] [--format text|json]
+       aoc all [<day>...] [--root <dir>] [--threads <n>] [--format text|json]
+       aoc bench [<day>...] [--iterations <n>] [--input <path>] [--root <dir>] [--json <path>]
+       aoc verify [<day>...] [--root <dir>] [--answers <path>]
+       aoc stress [<day>...] [--runs <n>] [--size <n>] [--seed <n>]
+       aoc generate <day> [--size <n>] [--seed <n>]
+
+commands:
+    run <day>           Solve both parts of <day> and print the answers
+    all [<day>...]      Solve each of the days (or every day) at the same time, each on its own
+                        thread, and print a table of the answers in day order. A day that panics
+                        is reported and the rest carry on. Fails if any day isn't solved.
+    bench [<day>...]    Time parsing and solving each of the days (or every day), and print the
+                        min, median and max time of each step
+    verify [<day>...]   Solve each of the days (or every day) and check the answers against the
+                        answers file. Fails if any answer doesn't match.
+    stress [<day>...]   Solve random inputs for each of the days (or every day) and report any
+                        that panic, with the seed that generated them. Fails if any do. Day 15 is
+                        solved with the example's row and search area, since the real ones are
+                        too big to solve over and over.
+    generate <day>      Print the random input that stress would solve for <day> with <seed>
+
+options:
+    -i, --input <path>      Read the puzzle input from <path>. Without it, or with `-`, the input
+                            is read from stdin. Bench only takes this with a single day.
+        --threads <n>       How many days all solves at once. Defaults to the number of CPUs.
+        --format <format>   How run and all print the answers: `text` (the default), or `json` for one
+                            object per part like
+                            {\"day\":10,\"part\":2,\"answer\":...,\"elapsed_us\":...}
+    -n, --iterations <n>    How many times bench solves each day. Defaults to 10.
+        --root <dir>        Where bench and verify find each day's input, at
+                            `day-NN/src/input.txt`. Defaults to the current directory.
+        --json <path>       Also write the bench results to <path> as JSON
+        --answers <path>    The answers for verify to check against. Defaults to `answers.txt`
+                            under the root.
+        --runs <n>          How many random inputs stress solves for each day. Defaults to 100.
+        --size <n>          Roughly how big the random inputs are. Defaults to 20.
+        --seed <n>          The seed for the random inputs. Stress solves the inputs for <n>,
+                            <n> + 1, and so on, starting from a seed based on the time if this
+                            isn't given. Generate defaults to 0.
+";
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum Command {
+    Run {
+        day: u8,
+        input: Source,
+        format: Format,
+    },
+    All(All),
+    Bench(Bench),
+    Verify(Verify),
+    Stress(Stress),
+    Generate(Generate),
+    Help,
+}
+
+/// Which days `aoc all` should solve, where to find their inputs, and how to show the results.
+#[derive(Debug, Eq, PartialEq)]
+pub struct All {
+    /// Every day, if empty.
+    pub days: Vec<u8>,
+    pub root: PathBuf,
+    /// The number of CPUs, if `None`.
+    pub threads: Option<usize>,
+    pub format: Format,
+}
+
+impl All {
+    pub fn source(&self, day: u8) -> Source {
+        Source::for_day(&self.root, day)
+    }
+}
+
+/// What `aoc bench` should time, and where to find the inputs.
+#[derive(Debug, Eq, PartialEq)]
+pub struct Bench {
+    /// Every day, if empty.
+    pub days: Vec<u8>,
+    pub iterations: usize,
+    /// The input for the only day, instead of looking under `root`.
+    pub input: Option<Source>,
+    pub root: PathBuf,
+    pub json: Option<PathBuf>,
+}
+
+impl Bench {
+    pub const DEFAULT_ITERATIONS: usize = 10;
+
+    pub fn source(&self, day: u8) -> Source {
+        match &self.input {
+            Some(input) => input.clone(),
+            None => Source::for_day(&self.root, day),
+        }
+    }
+}
+
+/// What `aoc verify` should check, and where to find the inputs and answers.
+#[derive(Debug, Eq, PartialEq)]
+pub struct Verify {
+    /// Every day, if empty.
+    pub days: Vec<u8>,
+    pub root: PathBuf,
+    pub answers: Option<PathBuf>,
+}
+
+impl Verify {
+    pub fn source(&self, day: u8) -> Source {
+        Source::for_day(&self.root, day)
+    }
+
+    pub fn answers_path(&self) -> PathBuf {
+        match &self.answers {
+            Some(answers) => answers.clone(),
+            None => self.root.join("answers.txt"),
+        }
+    }
+}
+
+/// What `aoc stress` should solve, and how many random inputs of what size.
+#[derive(Debug, Eq, PartialEq)]
+pub struct Stress {
+    /// Every day, if empty.
+    pub days: Vec<u8>,
+    pub runs: u64,
+    pub size: usize,
+    /// Where the seeds start, or a seed based on the time if `None`.
+    pub seed: Option<u64>,
+}
+
+impl Stress {
+    pub const DEFAULT_RUNS: u64 = 100;
+    pub const DEFAULT_SIZE: usize = 20;
+}
+
+/// Which random input `aoc generate` should print.
+#[derive(Debug, Eq, PartialEq)]
+pub struct Generate {
+    pub day: u8,
+    pub size: usize,
+    pub seed: u64,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct UsageError(String);
+
+impl Display for UsageError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for UsageError {}
+
+impl Command {
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, UsageError> {
+        let mut args = args.into_iter();
+        let Some(command) = args.next() else {
+            return Err(UsageError(String::from("missing command")));
+        };
+        match command.as_str() {
+            "run" => Self::parse_run(args),
+            "all" => Self::parse_all(args),
+            "bench" => Self::parse_bench(args),
+            "verify" => Self::parse_verify(args),
+            "stress" => Self::parse_stress(args),
+            "generate" => Self::parse_generate(args),
+            "help" | "-h" | "--help" => Ok(Command::Help),
+            other => Err(UsageError(format!("unknown command `{other}`"))),
+        }
+    }
+
+    fn parse_run(args: impl Iterator<Item = String>) -> Result<Self, UsageError> {
+        let mut day = None;
+        let mut input = Source::Stdin;
+        let mut format = Format::Text;
+        let mut args = args;
+        while let Some(arg) = args.next() {
+            if arg == "-i" || arg == "--input" {
+                let path = args
+                    .next()
+                    .ok_or_else(|| UsageError(format!("`{arg}` needs a path")))?;
+                input = Source::from_arg(&path);
+            } else if let Some(path) = arg.strip_prefix("--input=") {
+                input = Source::from_arg(path);
+            } else if arg == "--format" {
+                let value = args
+                    .next()
+                    .ok_or_else(|| UsageError(format!("`{arg}` needs a value")))?;
+                format = value.parse().map_err(UsageError)?;
+            } else if let Some(value) = arg.strip_prefix("--format=") {
+                format = value.parse().map_err(UsageError)?;
+            } else if day.is_none() {
+                day = Some(parse_day(&arg)?);
+            } else {
+                return Err(UsageError(format!("unexpected argument `{arg}`")));
+            }
+        }
+        let day = day.ok_or_else(|| UsageError(String::from("missing the day to run")))?;
+        Ok(Command::Run { day, input, format })
+    }
+
+    fn parse_all(args: impl Iterator<Item = String>) -> Result<Self, UsageError> {
+        let mut all = All {
+            days: Vec::new(),
+            root: PathBuf::from("."),
+            threads: None,
+            format: Format::Text,
+        };
+        for arg in split_options(args)? {
+            match arg {
+                Arg::Positional(day) => all.days.push(parse_day(&day)?),
+                Arg::Option { flag, value } => match flag.as_str() {
+                    "--root" => all.root = PathBuf::from(value),
+                    "--threads" => {
+                        all.threads = match value.parse() {
+                            Ok(0) | Err(_) => {
+                                return Err(UsageError(format!(
+                                    "`{value}` is not a positive number of threads"
+                                )))
+                            }
+                            Ok(threads) => Some(threads),
+                        };
+                    }
+                    "--format" => all.format = value.parse().map_err(UsageError)?,
+                    _ => return Err(UsageError(format!("unknown option `{flag}`"))),
+                },
+            }
+        }
+        Ok(Command::All(all))
+    }
+
+    fn parse_bench(args: impl Iterator<Item = String>) -> Result<Self, UsageError> {
+        let mut bench = Bench {
+            days: Vec::new(),
+            iterations: Bench::DEFAULT_ITERATIONS,
+            input: None,
+            root: PathBuf::from("."),
+            json: None,
+        };
+        for arg in split_options(args)? {
+            let (flag, value) = match arg {
+                Arg::Positional(day) => {
+                    bench.days.push(parse_day(&day)?);
+                    continue;
+                }
+                Arg::Option { flag, value } => (flag, value),
+            };
+            match flag.as_str() {
+                "-i" | "--input" => bench.input = Some(Source::from_arg(&value)),
+                "-n" | "--iterations" => {
+                    bench.iterations = match value.parse() {
+                        Ok(0) | Err(_) => {
+                            return Err(UsageError(format!(
+                                "`{value}` is not a positive number of iterations"
+                            )))
+                        }
+                        Ok(iterations) => iterations,
+                    };
+                }
+                "--root" => bench.root = PathBuf::from(value),
+                "--json" => bench.json = Some(PathBuf::from(value)),
+                _ => return Err(UsageError(format!("unknown option `{flag}`"))),
+            }
+        }
+        if bench.input.is_some() && bench.days.len() != 1 {
+            return Err(UsageError(String::from(
+                "`--input` needs exactly one day to bench",
+            )));
+        }
+        Ok(Command::Bench(bench))
+    }
+
+    fn parse_verify(args: impl Iterator<Item = String>) -> Result<Self, UsageError> {
+        let mut verify = Verify {
+            days: Vec::new(),
+            root: PathBuf::from("."),
+            answers: None,
+        };
+        for arg in split_options(args)? {
+            match arg {
+                Arg::Positional(day) => verify.days.push(parse_day(&day)?),
+                Arg::Option { flag, value } => match flag.as_str() {
+                    "--root" => verify.root = PathBuf::from(value),
+                    "--answers" => verify.answers = Some(PathBuf::from(value)),
+                    _ => return Err(UsageError(format!("unknown option `{flag}`"))),
+                },
+            }
+        }
+        Ok(Command::Verify(verify))
+    }
+
+    fn parse_stress(args: impl Iterator<Item = String>) -> Result<Self, UsageError> {
+        let mut stress = Stress {
+            days: Vec::new(),
+            runs: Stress::DEFAULT_RUNS,
+            size: Stress::DEFAULT_SIZE,
+            seed: None,
+        };
+        for arg in split_options(args)? {
+            match arg {
+                Arg::Positional(day) => stress.days.push(parse_day(&day)?),
+                Arg::Option { flag, value } => match flag.as_str() {
+                    "--runs" => stress.runs = parse_number(&value, "number of runs")?,
+                    "--size" => stress.size = parse_number(&value, "size")?,
+                    "--seed" => stress.seed = Some(parse_number(&value, "seed")?),
+                    _ => return Err(UsageError(format!("unknown option `{flag}`"))),
+                },
+            }
+        }
+        Ok(Command::Stress(stress))
+    }
+
+    fn parse_generate(args: impl Iterator<Item = String>) -> Result<Self, UsageError> {
+        let mut day = None;
+        let mut size = Stress::DEFAULT_SIZE;
+        let mut seed = 0;
+        for arg in split_options(args)? {
+            match arg {
+                Arg::Positional(arg) if day.is_none() => day = Some(parse_day(&arg)?),
+                Arg::Positional(arg) => {
+                    return Err(UsageError(format!("unexpected argument `{arg}`")))
+                }
+                Arg::Option { flag, value } => match flag.as_str() {
+                    "--size" => size = parse_number(&value, "size")?,
+                    "--seed" => seed = parse_number(&value, "seed")?,
+                    _ => return Err(UsageError(format!("unknown option `{flag}`"))),
+                },
+            }
+        }
+        let day = day.ok_or_else(|| UsageError(String::from("missing the day to generate")))?;
+        Ok(Command::Generate(Generate { day, size, seed }))
+    }
+}
+
+/// A command line argument, with an option's value already attached to it.
+enum Arg {
+    Positional(String),
+    Option { flag: String, value: String },
+}
+
+/// Pair every option with its value, given either as `--flag value` or `--flag=value`. Every
+/// option takes a value, and `-` on its own is positional.
+fn split_options(args: impl Iterator<Item = String>) -> Result<Vec<Arg>, UsageError> {
+    let mut args = args;
+    let mut split = Vec::new();
+    while let Some(arg) = args.next() {
+        if !arg.starts_with('-') || arg == "-" {
+            split.push(Arg::Positional(arg));
+            continue;
+        }
+        let (flag, value) = match arg.split_once('=') {
+            Some((flag, value)) => (flag.to_string(), value.to_string()),
+            None => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| UsageError(format!("`{arg}` needs a value")))?;
+                (arg, value)
+            }
+        };
+        split.push(Arg::Option { flag, value });
+    }
+    Ok(split)
+}
+
+fn parse_number<T: std::str::FromStr>(arg: &str, what: &str) -> Result<T, UsageError> {
+    arg.parse()
+        .map_err(|_| UsageError(format!("`{arg}` is not a valid {what}")))
+}
+
+fn parse_day(arg: &str) -> Result<u8, UsageError> {
+    arg.parse()
+        .map_err(|_| UsageError(format!("`{arg}` is not a day number")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn parse(args: &[&str]) -> Result<Command, UsageError> {
+        Command::parse(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn run() {
+        assert_eq!(
+            parse(&["run", "14", "--input", "path/to/file"]),
+            Ok(Command::Run {
+                day: 14,
+                input: Source::File(PathBuf::from("path/to/file")),
+                format: Format::Text,
+            })
+        );
+        assert_eq!(
+            parse(&["run", "--input=input.txt", "7"]),
+            Ok(Command::Run {
+                day: 7,
+                input: Source::File(PathBuf::from("input.txt")),
+                format: Format::Text,
+            })
+        );
+        assert_eq!(
+            parse(&["run", "1"]),
+            Ok(Command::Run {
+                day: 1,
+                input: Source::Stdin,
+                format: Format::Text,
+            })
+        );
+        assert_eq!(
+            parse(&["run", "1", "-i", "-"]),
+            Ok(Command::Run {
+                day: 1,
+                input: Source::Stdin,
+                format: Format::Text,
+            })
+        );
+        assert_eq!(
+            parse(&["run", "10", "--format", "json"]),
+            Ok(Command::Run {
+                day: 10,
+                input: Source::Stdin,
+                format: Format::Json,
+            })
+        );
+        assert!(parse(&["run", "10", "--format=csv"]).is_err());
+    }
+
+    #[test]
+    fn all() {
+        assert_eq!(
+            parse(&["all"]),
+            Ok(Command::All(All {
+                days: vec![],
+                root: PathBuf::from("."),
+                threads: None,
+                format: Format::Text,
+            }))
+        );
+        let Ok(Command::All(all)) = parse(&["all", "21", "3", "--threads=2", "--format", "json"])
+        else {
+            panic!("expected an all command");
+        };
+        assert_eq!(all.days, vec![21, 3]);
+        assert_eq!(all.threads, Some(2));
+        assert_eq!(all.format, Format::Json);
+
+        assert!(parse(&["all", "--threads", "0"]).is_err());
+        assert!(parse(&["all", "--input", "day-01.txt"]).is_err());
+    }
+
+    #[test]
+    fn bench() {
+        assert_eq!(
+            parse(&["bench"]),
+            Ok(Command::Bench(Bench {
+                days: vec![],
+                iterations: Bench::DEFAULT_ITERATIONS,
+                input: None,
+                root: PathBuf::from("."),
+                json: None,
+            }))
+        );
+        assert_eq!(
+            parse(&[
+                "bench",
+                "6",
+                "15",
+                "-n",
+                "50",
+                "--root=..",
+                "--json",
+                "bench.json"
+            ]),
+            Ok(Command::Bench(Bench {
+                days: vec![6, 15],
+                iterations: 50,
+                input: None,
+                root: PathBuf::from(".."),
+                json: Some(PathBuf::from("bench.json")),
+            }))
+        );
+        let Ok(Command::Bench(bench)) = parse(&["bench", "6", "--input", "day-06.txt"]) else {
+            panic!("expected a bench command");
+        };
+        assert_eq!(bench.source(6), Source::File(PathBuf::from("day-06.txt")));
+
+        assert!(parse(&["bench", "--iterations", "0"]).is_err());
+        assert!(parse(&["bench", "--iterations"]).is_err());
+        assert!(parse(&["bench", "--input", "day-06.txt"]).is_err());
+        assert!(parse(&["bench", "--fast"]).is_err());
+    }
+
+    #[test]
+    fn verify() {
+        let Ok(Command::Verify(verify)) = parse(&["verify", "--root", "aoc"]) else {
+            panic!("expected a verify command");
+        };
+        assert_eq!(verify.days, vec![]);
+        assert_eq!(verify.answers_path(), PathBuf::from("aoc/answers.txt"));
+        assert_eq!(
+            verify.source(3),
+            Source::File(PathBuf::from("aoc/day-03/src/input.txt"))
+        );
+
+        let Ok(Command::Verify(verify)) = parse(&["verify", "1", "2", "--answers=mine.txt"]) else {
+            panic!("expected a verify command");
+        };
+        assert_eq!(verify.days, vec![1, 2]);
+        assert_eq!(verify.answers_path(), PathBuf::from("mine.txt"));
+
+        assert!(parse(&["verify", "--iterations", "5"]).is_err());
+    }
+
+    #[test]
+    fn stress() {
+        assert_eq!(
+            parse(&["stress"]),
+            Ok(Command::Stress(Stress {
+                days: vec![],
+                runs: Stress::DEFAULT_RUNS,
+                size: Stress::DEFAULT_SIZE,
+                seed: None,
+            }))
+        );
+        assert_eq!(
+            parse(&[
+                "stress",
+                "5",
+                "13",
+                "--runs=1000",
+                "--size",
+                "5",
+                "--seed",
+                "42"
+            ]),
+            Ok(Command::Stress(Stress {
+                days: vec![5, 13],
+                runs: 1000,
+                size: 5,
+                seed: Some(42),
+            }))
+        );
+        assert!(parse(&["stress", "--seed", "-1"]).is_err());
+        assert!(parse(&["stress", "--input", "day-05.txt"]).is_err());
+    }
+
+    #[test]
+    fn generate() {
+        assert_eq!(
+            parse(&["generate", "13", "--seed", "7"]),
+            Ok(Command::Generate(Generate {
+                day: 13,
+                size: Stress::DEFAULT_SIZE,
+                seed: 7,
+            }))
+        );
+        assert!(parse(&["generate"]).is_err());
+        assert!(parse(&["generate", "1", "2"]).is_err());
+        assert!(parse(&["generate", "1", "--runs", "2"]).is_err());
+    }
+
+    #[test]
+    fn usage_errors() {
+        assert!(parse(&[]).is_err());
+        assert!(parse(&["walk", "1"]).is_err());
+        assert!(parse(&["run"]).is_err());
+        assert!(parse(&["run", "one"]).is_err());
+        assert!(parse(&["run", "1", "2"]).is_err());
+        assert!(parse(&["run", "1", "--input"]).is_err());
+    }
+}
@@ -0,0 +1,85 @@
+use aoc_core::Solver;
+
+/// Every day that has a solution in the workspace, in order.
+pub fn all() -> Vec<Box<dyn Solver>> {
+    vec![
+        Box::new(day_01::Day01),
+        Box::new(day_02::Day02),
+        Box::new(day_03::Day03),
+        Box::new(day_04::Day04),
+        Box::new(day_05::Day05),
+        Box::new(day_06::Day06),
+        Box::new(day_07::Day07),
+        Box::new(day_08::Day08),
+        Box::new(day_09::Day09),
+        Box::new(day_10::Day10),
+        Box::new(day_11::Day11),
+        Box::new(day_12::Day12),
+        Box::new(day_13::Day13),
+        Box::new(day_14::Day14),
+        Box::new(day_15::Day15::default()),
+        Box::new(day_18::Day18),
+        Box::new(day_21::Day21),
+        Box::new(day_22::Day22),
+    ]
+}
+
+pub fn find(day: u8) -> Option<Box<dyn Solver>> {
+    all().into_iter().find(|solver| solver.day() == day)
+}
+
+/// The given days, in the order given, or every day if none are.
+pub fn select(days: &[u8]) -> Result<Vec<Box<dyn Solver>>, UnknownDay> {
+    if days.is_empty() {
+        return Ok(all());
+    }
+    days.iter()
+        .map(|&day| find(day).ok_or(UnknownDay(day)))
+        .collect()
+}
+
+/// Like [`select`], but with every day set up to solve small random inputs quickly. Day 15's real
+/// row and search area are millions wide, so it gets the example's instead.
+pub fn for_stress(days: &[u8]) -> Result<Vec<Box<dyn Solver>>, UnknownDay> {
+    let solvers = select(days)?;
+    Ok(solvers
+        .into_iter()
+        .map(|solver| match solver.day() {
+            15 => Box::new(day_15::Day15::EXAMPLE),
+            _ => solver,
+        })
+        .collect())
+}
+
+#[derive(Debug)]
+pub struct UnknownDay(pub u8);
+
+impl std::fmt::Display for UnknownDay {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "there is no solution for day {}; solved days are",
+            self.0
+        )?;
+        for solver in all() {
+            write!(f, " {}", solver.day())?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for UnknownDay {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn days_are_in_order() {
+        let days = all().iter().map(|solver| solver.day()).collect::<Vec<_>>();
+        let mut sorted = days.clone();
+        sorted.sort();
+        sorted.dedup();
+        assert_eq!(days, sorted);
+    }
+}
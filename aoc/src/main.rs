@@ -0,0 +1,93 @@
+mod all;
+mod answers;
+mod bench;
+mod cli;
+mod days;
+mod panics;
+mod stress;
+mod verify;
+
+use aoc_core::{args::Format, json, random::Rng};
+use cli::Command;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    let command = match Command::parse(std::env::args().skip(1)) {
+        Ok(command) => command,
+        Err(err) => {
+            eprintln!("error: {err}\n\n{}", cli::USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match run(command) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(command: Command) -> Result<(), Box<dyn std::error::Error>> {
+    match command {
+        Command::Help => print!("{}", cli::USAGE),
+        Command::Run { day, input, format } => {
+            let solver = days::find(day).ok_or(days::UnknownDay(day))?;
+            let input = input.read()?;
+            let solutions = solver
+                .solve_timed(&input)
+                .map_err(|err| format!("failed to parse the puzzle input for day {day}: {err}"))?;
+            match format {
+                Format::Text => {
+                    println!("Day {day}");
+                    for solution in &solutions {
+                        print_answer(solution.part, &solution.answer);
+                    }
+                }
+                Format::Json => {
+                    for solution in &solutions {
+                        let line =
+                            json::answer_line(day, solution.part, &solution.json, solution.elapsed);
+                        println!("{line}");
+                    }
+                }
+            }
+        }
+        Command::All(all) => {
+            if !all::run(&all)? {
+                return Err("some days couldn't be solved".into());
+            }
+        }
+        Command::Bench(bench) => bench::run(&bench)?,
+        Command::Verify(verify) => {
+            if !verify::run(&verify)? {
+                return Err("some answers are wrong or couldn't be checked".into());
+            }
+        }
+        Command::Stress(stress) => {
+            if !stress::run(&stress)? {
+                return Err("some random inputs couldn't be solved".into());
+            }
+        }
+        Command::Generate(generate) => {
+            let solvers = days::for_stress(&[generate.day])?;
+            let mut rng = Rng::new(generate.seed);
+            let input = solvers[0]
+                .generate(&mut rng, generate.size)
+                .ok_or_else(|| format!("day {} has no generator", generate.day))?;
+            print!("{input}");
+        }
+    }
+    Ok(())
+}
+
+fn print_answer(part: u8, answer: &str) {
+    // Some answers, like the CRT on day 10, are pictures. Start those on their own line so the
+    // first row lines up with the rest.
+    if answer.contains('\n') {
+        println!("Part {part}:\n{}", answer.trim_end());
+    } else {
+        println!("Part {part}: {answer}");
+    }
+}
@@ -0,0 +1,93 @@
+//! Catching panics from solutions, so that one day going wrong doesn't take the others with it.
+
+use std::{
+    cell::{Cell, RefCell},
+    panic::{self, AssertUnwindSafe},
+    sync::OnceLock,
+};
+
+thread_local! {
+    /// Whether this thread is inside [`catch`], so its panics are recorded instead of printed.
+    static CAPTURING: Cell<bool> = const { Cell::new(false) };
+    /// What the last panic on this thread said, and where.
+    static LAST_PANIC: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Install the recording panic hook, the first time it's needed. Panics on threads that aren't
+/// inside [`catch`] still go to whichever hook was there before, so they print as usual.
+///
+/// The hook is never swapped back out: threads catching panics at the same time would otherwise
+/// restore each other's hooks in the wrong order.
+fn install_hook() {
+    static INSTALLED: OnceLock<()> = OnceLock::new();
+    INSTALLED.get_or_init(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !CAPTURING.with(Cell::get) {
+                return previous(info);
+            }
+            let message = match info.payload().downcast_ref::<&str>() {
+                Some(message) => message.to_string(),
+                None => match info.payload().downcast_ref::<String>() {
+                    Some(message) => message.clone(),
+                    None => String::from("(no message)"),
+                },
+            };
+            let message = match info.location() {
+                Some(location) => format!("{message} at {location}"),
+                None => message,
+            };
+            LAST_PANIC.with(|last| *last.borrow_mut() = Some(message));
+        }));
+    });
+}
+
+/// Run `f`, turning a panic into what it said and where instead of printing it.
+pub fn catch<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    install_hook();
+    let was_capturing = CAPTURING.with(|capturing| capturing.replace(true));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    CAPTURING.with(|capturing| capturing.set(was_capturing));
+    result.map_err(|_| {
+        LAST_PANIC
+            .with(|last| last.borrow_mut().take())
+            .unwrap_or_default()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    #[test]
+    fn test_catch() {
+        let caught = catch(|| -> u8 { panic!("oh no") });
+        let message = caught.unwrap_err();
+        assert!(message.starts_with("oh no at "), "{message}");
+        assert_eq!(catch(|| 7), Ok(7));
+        assert!(!CAPTURING.with(Cell::get));
+    }
+
+    #[test]
+    fn test_catch_on_many_threads() {
+        thread::scope(|scope| {
+            let threads = (0..8)
+                .map(|thread| {
+                    scope.spawn(move || {
+                        (0..50)
+                            .map(|run| catch(|| -> u8 { panic!("thread {thread} run {run}") }))
+                            .collect::<Vec<_>>()
+                    })
+                })
+                .collect::<Vec<_>>();
+            for (thread, handle) in threads.into_iter().enumerate() {
+                for (run, caught) in handle.join().unwrap().into_iter().enumerate() {
+                    let message = caught.unwrap_err();
+                    let expected = format!("thread {thread} run {run} at ");
+                    assert!(message.starts_with(&expected), "{message}");
+                }
+            }
+        });
+    }
+}
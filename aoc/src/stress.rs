@@ -0,0 +1,140 @@
+//! `aoc stress`: solve random inputs from each day's generator, and report every panic along with
+//! the seed that generated the input, so that it can be reproduced.
+
+use crate::{cli::Stress, days, panics};
+use aoc_core::{random::Rng, Solver};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// One way a day went wrong, and how often.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Failure {
+    pub message: String,
+    /// The seed of the first input that went wrong this way.
+    pub seed: u64,
+    pub count: u64,
+}
+
+/// Stress every day, print what went wrong, and say whether every input was solved.
+pub fn run(stress: &Stress) -> Result<bool, Box<dyn std::error::Error>> {
+    let solvers = days::for_stress(&stress.days)?;
+    let seed = stress.seed.unwrap_or_else(seed_from_time);
+    println!(
+        "solving {} random inputs of size {} for each day, from seed {seed}",
+        stress.runs, stress.size
+    );
+
+    let mut passed = true;
+    let mut failed_days = Vec::new();
+    for solver in solvers {
+        let day = solver.day();
+        let Some(failures) = stress_day(&*solver, seed, stress.runs, stress.size) else {
+            println!("{day:>3}  no generator");
+            continue;
+        };
+        if failures.is_empty() {
+            println!("{day:>3}  ok");
+            continue;
+        }
+        passed = false;
+        let count = failures.iter().map(|failure| failure.count).sum::<u64>();
+        println!("{day:>3}  {count} of {} failed", stress.runs);
+        for failure in &failures {
+            println!(
+                "       {}x (first with seed {}): {}",
+                failure.count, failure.seed, failure.message
+            );
+        }
+        failed_days.push((day, failures[0].seed));
+    }
+
+    if let Some((day, seed)) = failed_days.first() {
+        println!(
+            "\nto see a failing input, run `aoc generate {day} --seed {seed} --size {}`",
+            stress.size
+        );
+    }
+    Ok(passed)
+}
+
+/// Solve `runs` random inputs, starting from `seed`, and group what went wrong by message. None
+/// if the day has no generator.
+pub fn stress_day(solver: &dyn Solver, seed: u64, runs: u64, size: usize) -> Option<Vec<Failure>> {
+    let mut failures: Vec<Failure> = Vec::new();
+    for run in 0..runs {
+        let seed = seed.wrapping_add(run);
+        let input = solver.generate(&mut Rng::new(seed), size)?;
+        let message = match panics::catch(|| solver.solve(&input)) {
+            Ok(Ok(_)) => continue,
+            Ok(Err(err)) => format!("failed to parse the generated input: {}", err.message()),
+            Err(message) => format!("panicked: {message}"),
+        };
+        match failures
+            .iter_mut()
+            .find(|failure| failure.message == message)
+        {
+            Some(failure) => failure.count += 1,
+            None => failures.push(Failure {
+                message,
+                seed,
+                count: 1,
+            }),
+        }
+    }
+    Some(failures)
+}
+
+fn seed_from_time() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_nanos() as u64)
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::{Day, ParseError};
+
+    /// Panics on every input with an odd number in it.
+    struct Fussy;
+
+    impl Day for Fussy {
+        const DAY: u8 = 1;
+        type Input<'a> = u64;
+        type Part1 = u64;
+        type Part2 = u64;
+
+        fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+            input
+                .trim()
+                .parse()
+                .map_err(|_| ParseError::expected("a number", input))
+        }
+
+        fn part_1(&self, input: &Self::Input<'_>) -> Self::Part1 {
+            assert!(input % 2 == 0, "odd");
+            *input
+        }
+
+        fn part_2(&self, input: &Self::Input<'_>) -> Self::Part2 {
+            *input
+        }
+
+        fn generate(&self, rng: &mut Rng, _size: usize) -> Option<String> {
+            Some(rng.below(10).to_string())
+        }
+    }
+
+    #[test]
+    fn test_stress_day() {
+        let failures = stress_day(&Fussy, 0, 50, 1).unwrap();
+        assert_eq!(failures.len(), 1);
+        let failure = &failures[0];
+        assert!(failure.message.starts_with("panicked: odd at "));
+        assert!(failure.count > 0 && failure.count < 50);
+
+        // The seed reproduces the input.
+        let input = Day::generate(&Fussy, &mut Rng::new(failure.seed), 1).unwrap();
+        assert!(input.parse::<u64>().unwrap() % 2 == 1);
+    }
+}
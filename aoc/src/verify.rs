@@ -0,0 +1,135 @@
+//! `aoc verify`: solve every day's real input and check the answers against the registry.
+
+use crate::{answers::Registry, cli::Verify, days};
+use aoc_core::{input::Source, Answers, Solver};
+use std::fmt::{Display, Write};
+
+/// How one part's answer compares with the registry.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Status {
+    Pass,
+    Fail {
+        expected: String,
+        actual: String,
+    },
+    /// There's no answer in the registry to check against.
+    Missing,
+}
+
+impl Status {
+    pub fn check(registry: &Registry, day: u8, part: u8, actual: &str) -> Self {
+        let Some(expected) = registry.get(day, part) else {
+            return Status::Missing;
+        };
+        // Pictures end with a newline when they're drawn, but the registry doesn't keep it.
+        let actual = actual.trim_end();
+        if actual == expected {
+            Status::Pass
+        } else {
+            Status::Fail {
+                expected: expected.to_string(),
+                actual: actual.to_string(),
+            }
+        }
+    }
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.pad(match self {
+            Status::Pass => "pass",
+            Status::Fail { .. } => "FAIL",
+            Status::Missing => "missing",
+        })
+    }
+}
+
+/// Check every day, print a line for each, and say whether everything that could be checked
+/// passed.
+pub fn run(verify: &Verify) -> Result<bool, Box<dyn std::error::Error>> {
+    let path = verify.answers_path();
+    let registry = std::fs::read_to_string(&path)
+        .map_err(|err| format!("could not read the answers in `{}`: {err}", path.display()))?;
+    let registry = Registry::parse(&registry)
+        .map_err(|err| format!("could not parse the answers in `{}`: {err}", path.display()))?;
+
+    let solvers = days::select(&verify.days)?;
+
+    let mut passed = true;
+    let mut details = String::new();
+    println!("day  part 1   part 2");
+    for solver in solvers {
+        let day = solver.day();
+        let answers = match solve(day, &verify.source(day), &*solver) {
+            Ok(answers) => answers,
+            Err(err) => {
+                println!("{day:>3}  error    error");
+                writeln!(details, "day {day}: {err}")?;
+                passed = false;
+                continue;
+            }
+        };
+        let part_1 = Status::check(&registry, day, 1, &answers.part_1);
+        let part_2 = Status::check(&registry, day, 2, &answers.part_2);
+        println!("{day:>3}  {part_1:<8} {part_2}");
+        for (part, status) in [(1, part_1), (2, part_2)] {
+            if let Status::Fail { expected, actual } = status {
+                writeln!(
+                    details,
+                    "day {day} part {part}: {}",
+                    mismatch(&expected, &actual)
+                )?;
+                passed = false;
+            }
+        }
+    }
+    if !details.is_empty() {
+        print!("\n{details}");
+    }
+    Ok(passed)
+}
+
+fn solve(day: u8, source: &Source, solver: &dyn Solver) -> Result<Answers, String> {
+    let input = source.read().map_err(|err| err.to_string())?;
+    solver
+        .solve(&input)
+        .map_err(|err| format!("failed to parse the puzzle input for day {day}: {err}"))
+}
+
+/// What was expected and what came out instead. Pictures get a line each.
+fn mismatch(expected: &str, actual: &str) -> String {
+    if expected.contains('\n') || actual.contains('\n') {
+        format!("expected\n{expected}\nbut got\n{actual}")
+    } else {
+        format!("expected `{expected}`, but got `{actual}`")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        let registry = Registry::parse("day 1 part 1: 24000\nday 10 part 2:\n    ##\n").unwrap();
+        assert_eq!(Status::check(&registry, 1, 1, "24000"), Status::Pass);
+        assert_eq!(
+            Status::check(&registry, 1, 1, "45000"),
+            Status::Fail {
+                expected: String::from("24000"),
+                actual: String::from("45000")
+            }
+        );
+        assert_eq!(Status::check(&registry, 1, 2, "45000"), Status::Missing);
+        assert_eq!(Status::check(&registry, 10, 2, "##\n"), Status::Pass);
+    }
+
+    #[test]
+    fn test_mismatch() {
+        assert_eq!(mismatch("1", "2"), "expected `1`, but got `2`");
+        assert_eq!(
+            mismatch("#.\n.#", "##\n.#"),
+            "expected\n#.\n.#\nbut got\n##\n.#"
+        );
+    }
+}
@@ -0,0 +1,31 @@
+//! Random calorie lists: a blank-line-separated group of snacks per elf.
+
+use aoc_core::random::Rng;
+
+/// Up to `size` elves, each carrying a handful of snacks.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let elves = 1 + rng.below(size.max(1));
+    let mut groups = Vec::with_capacity(elves);
+    for _ in 0..elves {
+        let snacks = (0..1 + rng.below(8))
+            .map(|_| rng.range(1..=60_000).to_string())
+            .collect::<Vec<_>>();
+        groups.push(snacks.join("\n"));
+    }
+    groups.join("\n\n") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day01;
+    use aoc_core::Day;
+
+    #[test]
+    fn test_parses() {
+        for seed in 0..20 {
+            let input = input(&mut Rng::new(seed), 10);
+            assert!(Day01.parse(&input).is_ok(), "seed {seed}:\n{input}");
+        }
+    }
+}
@@ -0,0 +1,146 @@
+//! What each elf carries, parsed once and kept in the order the elves are listed.
+
+use super::{add_snack, parse_line};
+use aoc_core::ParseError;
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+/// The snacks every elf carries, and their totals. Elves are numbered from 0 in the order they
+/// appear in the list; blank lines with no snacks between them don't count as an elf.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct ElfInventory {
+    items: Vec<Vec<u64>>,
+    totals: Vec<u64>,
+}
+
+impl ElfInventory {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut inventory = Self::default();
+        let mut current = Vec::new();
+        let mut total = 0;
+        for line in input.lines() {
+            match parse_line(line)? {
+                Some(calories) => {
+                    total = add_snack(total, calories, line)?;
+                    current.push(calories);
+                }
+                None => inventory.push(std::mem::take(&mut current), std::mem::take(&mut total)),
+            }
+        }
+        inventory.push(current, total);
+        Ok(inventory)
+    }
+
+    fn push(&mut self, items: Vec<u64>, total: u64) {
+        if !items.is_empty() {
+            self.totals.push(total);
+            self.items.push(items);
+        }
+    }
+
+    /// How many elves there are.
+    pub fn len(&self) -> usize {
+        self.totals.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.totals.is_empty()
+    }
+
+    /// The calories each elf carries, by elf.
+    pub fn totals(&self) -> &[u64] {
+        &self.totals
+    }
+
+    /// The calories of each snack `elf` carries, in the order they were listed.
+    pub fn items(&self, elf: usize) -> &[u64] {
+        &self.items[elf]
+    }
+
+    /// The `k` elves carrying the most, as `(elf, total)` from the most to the least. Elves
+    /// carrying the same amount are all kept, the earlier one first, as far as `k` allows.
+    pub fn top_k(&self, k: usize) -> Vec<(usize, u64)> {
+        let mut top = TopK::new(k);
+        for (elf, &total) in self.totals.iter().enumerate() {
+            top.push(elf, total);
+        }
+        top.into_sorted_vec()
+    }
+}
+
+/// The `k` biggest totals pushed so far, with the elves carrying them. Only `k` are ever held, in
+/// a min-heap with the smallest on top so it's the one to go when a bigger one comes along.
+#[derive(Debug)]
+pub(crate) struct TopK {
+    k: usize,
+    heap: BinaryHeap<Reverse<(u64, Reverse<usize>)>>,
+}
+
+impl TopK {
+    pub(crate) fn new(k: usize) -> Self {
+        Self {
+            k,
+            heap: BinaryHeap::with_capacity(k),
+        }
+    }
+
+    /// Keep `elf`'s `total` if it's among the `k` biggest. On a tie the elf already kept stays,
+    /// so elves should be pushed in order.
+    pub(crate) fn push(&mut self, elf: usize, total: u64) {
+        let entry = Reverse((total, Reverse(elf)));
+        if self.heap.len() < self.k {
+            self.heap.push(entry);
+        } else if let Some(mut smallest) = self.heap.peek_mut() {
+            if entry < *smallest {
+                *smallest = entry;
+            }
+        }
+    }
+
+    /// The kept `(elf, total)` pairs, biggest total first.
+    pub(crate) fn into_sorted_vec(self) -> Vec<(usize, u64)> {
+        self.heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse((total, Reverse(elf)))| (elf, total))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let inventory = ElfInventory::parse(include_str!("example.txt")).unwrap();
+        assert_eq!(inventory.totals(), [6_000, 4_000, 11_000, 24_000, 10_000]);
+        assert_eq!(inventory.items(2), [5_000, 6_000]);
+
+        let inventory = ElfInventory::parse("\n1\n\n\n2\n3\n\n").unwrap();
+        assert_eq!(inventory.totals(), [1, 5]);
+        assert!(ElfInventory::parse("").unwrap().is_empty());
+
+        let input = "1\n\n18446744073709551615\n18446744073709551615\n";
+        let err = ElfInventory::parse(input).unwrap_err().locate(input);
+        assert_eq!(err.location().map(|location| location.line()), Some(4));
+        assert_eq!(
+            err.message(),
+            "an elf carries more than 18446744073709551615 calories"
+        );
+    }
+
+    #[test]
+    fn test_top_k() {
+        let inventory = ElfInventory::parse(include_str!("example.txt")).unwrap();
+        assert_eq!(inventory.top_k(3), [(3, 24_000), (2, 11_000), (4, 10_000)]);
+        assert_eq!(inventory.top_k(0), []);
+        assert_eq!(inventory.top_k(10).len(), 5);
+    }
+
+    #[test]
+    fn test_top_k_keeps_ties() {
+        let inventory = ElfInventory::parse("1\n\n5\n\n5\n\n2\n3\n\n5").unwrap();
+        assert_eq!(inventory.top_k(3), [(1, 5), (2, 5), (3, 5)]);
+        assert_eq!(inventory.top_k(2), [(1, 5), (2, 5)]);
+    }
+}
@@ -0,0 +1,144 @@
+//! Day 1: Calorie Counting.
+
+pub mod generate;
+pub mod inventory;
+pub mod rebalance;
+pub mod stats;
+
+use aoc_core::{
+    random::Rng,
+    stream::{self, StreamError},
+    Day, ParseError, StreamingDay,
+};
+use inventory::{ElfInventory, TopK};
+use std::io::BufRead;
+
+/// Calorie Counting: the elves list the calories of each snack they carry, one elf per
+/// blank-line-separated group.
+pub struct Day01;
+
+impl Day for Day01 {
+    const DAY: u8 = 1;
+    type Input<'a> = ElfInventory;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        ElfInventory::parse(input)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>) -> Self::Part1 {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>) -> Self::Part2 {
+        part_2(input)
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+}
+
+/// Only the three biggest totals seen so far are kept, however many elves there are.
+impl StreamingDay for Day01 {
+    fn solve_stream(&self, reader: impl BufRead) -> Result<(u64, u64), StreamError> {
+        let mut top = TopK::new(3);
+        let mut elves = 0;
+        let mut current = None;
+        // Each line gives back the total of the elf it finishes, if it finishes one.
+        let totals = stream::parse_lines(reader, |line| match parse_line(line)? {
+            Some(calories) => {
+                current = Some(add_snack(current.unwrap_or(0), calories, line)?);
+                Ok::<_, ParseError>(None)
+            }
+            None => Ok(current.take()),
+        });
+        for total in totals {
+            if let Some(total) = total? {
+                top.push(elves, total);
+                elves += 1;
+            }
+        }
+        if let Some(total) = current {
+            top.push(elves, total);
+        }
+        let top = top.into_sorted_vec();
+        let part_1 = top.first().map_or(0, |&(_, total)| total);
+        Ok((part_1, top.iter().map(|&(_, total)| total).sum()))
+    }
+}
+
+/// A line of the list: a snack's calories, or `None` for the blank line between two elves.
+fn parse_line(line: &str) -> Result<Option<u64>, ParseError> {
+    if line.is_empty() {
+        return Ok(None);
+    }
+    line.parse()
+        .map(Some)
+        .map_err(|_| ParseError::expected("a calorie count", line))
+}
+
+/// `total` with another snack's `calories` added, or an error at the snack's `line` if the elf
+/// carrying it would have more calories than a `u64` holds.
+fn add_snack(total: u64, calories: u64, line: &str) -> Result<u64, ParseError> {
+    total.checked_add(calories).ok_or_else(|| {
+        ParseError::new(format!("an elf carries more than {} calories", u64::MAX)).within(line)
+    })
+}
+
+/// The most calories carried by a single elf.
+pub fn part_1(inventory: &ElfInventory) -> u64 {
+    inventory.top_k(1).iter().map(|&(_, total)| total).sum()
+}
+
+/// The calories carried by the three elves carrying the most.
+pub fn part_2(inventory: &ElfInventory) -> u64 {
+    inventory.top_k(3).iter().map(|&(_, total)| total).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve_stream() {
+        let input = include_str!("example.txt");
+        assert_eq!(
+            Day01.solve_stream(input.as_bytes()).unwrap(),
+            (24_000, 45_000)
+        );
+        assert_eq!(Day01.solve_stream("".as_bytes()).unwrap(), (0, 0));
+
+        let err = Day01.solve_stream("1\n\n2x\n".as_bytes()).unwrap_err();
+        let StreamError::Parse(err) = err else {
+            panic!("expected a parse error");
+        };
+        assert_eq!(err.location().map(|location| location.line()), Some(3));
+
+        let err = Day01
+            .solve_stream("1\n\n18446744073709551615\n1\n".as_bytes())
+            .unwrap_err();
+        let StreamError::Parse(err) = err else {
+            panic!("expected a parse error");
+        };
+        assert_eq!(err.location().map(|location| location.line()), Some(4));
+    }
+
+    #[test]
+    fn test_equal_totals() {
+        let input = "3000\n\n1000\n2000\n\n3000\n\n500";
+        let inventory = Day01.parse(input).unwrap();
+        assert_eq!(part_1(&inventory), 3_000);
+        assert_eq!(part_2(&inventory), 9_000);
+        assert_eq!(
+            Day01.solve_stream(input.as_bytes()).unwrap(),
+            (3_000, 9_000)
+        );
+    }
+
+    aoc_core::example_tests! {
+        test_part_1: Day01, "example.txt", part_1 => 24_000;
+        test_part_2: Day01, "example.txt", part_2 => 45_000;
+    }
+}
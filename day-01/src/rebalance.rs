@@ -0,0 +1,363 @@
+//! `day-01 rebalance`: share every snack out between a number of carriers so that the one carrying
+//! the most carries as little as possible.
+
+use crate::inventory::ElfInventory;
+use aoc_core::{
+    args,
+    input::{self, Source},
+};
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashSet},
+    fmt::Write,
+};
+
+/// Up to this many snacks, the best plan is searched for; past it, the search could take forever,
+/// so the plan is only a good one.
+pub const EXACT_LIMIT: usize = 20;
+
+/// One snack, and where it came from.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Snack {
+    pub elf: usize,
+    /// Which of the elf's snacks it is, in the order they were listed.
+    pub index: usize,
+    pub calories: u64,
+}
+
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct Carrier {
+    pub snacks: Vec<Snack>,
+    pub total: u64,
+}
+
+/// How the plan was made.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Method {
+    /// Branch and bound: no plan has a lighter heaviest carrier.
+    Exact,
+    /// Longest processing time first: each snack, biggest first, goes to whoever carries the least
+    /// so far. The heaviest carrier is at most 4/3 as heavy as it has to be.
+    Lpt,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Plan {
+    pub method: Method,
+    /// The carriers given snacks, at most one per snack. The rest carry nothing, so they're only
+    /// counted in `idle`.
+    pub carriers: Vec<Carrier>,
+    pub idle: usize,
+}
+
+impl Plan {
+    /// What the heaviest carrier carries.
+    pub fn max(&self) -> u64 {
+        self.carriers
+            .iter()
+            .map(|carrier| carrier.total)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// The plan for people, with elves and snacks numbered from 1.
+    pub fn to_text(&self) -> String {
+        let mut text = String::new();
+        let digits = self.max().to_string().len();
+        for (number, carrier) in self.carriers.iter().enumerate() {
+            let snacks = carrier
+                .snacks
+                .iter()
+                .map(|snack| {
+                    format!(
+                        "{} (elf {} #{})",
+                        snack.calories,
+                        snack.elf + 1,
+                        snack.index + 1
+                    )
+                })
+                .collect::<Vec<_>>();
+            writeln!(
+                text,
+                "carrier {:<3} {:>digits$}  {}",
+                number + 1,
+                carrier.total,
+                snacks.join(", ")
+            )
+            .unwrap();
+        }
+        match self.idle {
+            0 => {}
+            1 => writeln!(text, "carrier {} carries nothing", self.carriers.len() + 1).unwrap(),
+            idle => writeln!(
+                text,
+                "carriers {} to {} carry nothing",
+                self.carriers.len() + 1,
+                self.carriers.len() + idle
+            )
+            .unwrap(),
+        }
+        let method = match self.method {
+            Method::Exact => "the best possible",
+            Method::Lpt => "found by LPT, maybe not the best",
+        };
+        writeln!(text, "heaviest carrier: {} ({method})", self.max()).unwrap();
+        text
+    }
+}
+
+/// Share out everything in `inventory` between `carriers` carriers, searching for the best plan
+/// if there are at most [`EXACT_LIMIT`] snacks. None if there are no carriers.
+pub fn plan(inventory: &ElfInventory, carriers: usize) -> Option<Plan> {
+    if carriers == 0 {
+        return None;
+    }
+    let mut snacks = (0..inventory.len())
+        .flat_map(|elf| {
+            inventory
+                .items(elf)
+                .iter()
+                .enumerate()
+                .map(move |(index, &calories)| Snack {
+                    elf,
+                    index,
+                    calories,
+                })
+        })
+        .collect::<Vec<_>>();
+    // Both work best with the big snacks placed first.
+    snacks.sort_by_key(|snack| Reverse(snack.calories));
+
+    if snacks.is_empty() {
+        return Some(Plan {
+            method: Method::Exact,
+            carriers: Vec::new(),
+            idle: carriers,
+        });
+    }
+
+    // A carrier past one per snack would have nothing to carry.
+    let used = carriers.min(snacks.len());
+    let lpt = lpt(&snacks, used);
+    let (method, assignment) = if snacks.len() > EXACT_LIMIT {
+        (Method::Lpt, lpt)
+    } else {
+        (Method::Exact, branch_and_bound(&snacks, used, lpt))
+    };
+    let mut plan = build(method, &snacks, &assignment, used);
+    plan.idle = carriers - used;
+    Some(plan)
+}
+
+/// Which carrier each snack goes to, by LPT.
+fn lpt(snacks: &[Snack], carriers: usize) -> Vec<usize> {
+    let mut loads = (0..carriers)
+        .map(|carrier| Reverse((0, carrier)))
+        .collect::<BinaryHeap<_>>();
+    snacks
+        .iter()
+        .map(|snack| {
+            let mut lightest = loads.peek_mut().expect("there are carriers");
+            let Reverse((load, carrier)) = *lightest;
+            *lightest = Reverse((load + snack.calories, carrier));
+            carrier
+        })
+        .collect()
+}
+
+/// Which carrier each snack goes to in a best plan, starting from the plan `start` to beat.
+fn branch_and_bound(snacks: &[Snack], carriers: usize, start: Vec<usize>) -> Vec<usize> {
+    let total = snacks.iter().map(|snack| snack.calories).sum::<u64>();
+    let largest = snacks.first().map_or(0, |snack| snack.calories);
+    let mut search = Search {
+        snacks,
+        loads: vec![0; carriers],
+        assignment: vec![0; snacks.len()],
+        best_max: max_load(snacks, &start, carriers),
+        best: start,
+        // No plan can beat sharing everything out evenly, or avoid carrying the biggest snack.
+        lower_bound: largest.max(total.div_ceil(carriers as u64)),
+    };
+    search.assign(0, 0);
+    search.best
+}
+
+struct Search<'a> {
+    snacks: &'a [Snack],
+    loads: Vec<u64>,
+    assignment: Vec<usize>,
+    best_max: u64,
+    best: Vec<usize>,
+    lower_bound: u64,
+}
+
+impl Search<'_> {
+    /// Try every carrier for snack `next` onwards, given the heaviest carrier so far.
+    fn assign(&mut self, next: usize, heaviest: u64) {
+        if self.best_max == self.lower_bound {
+            return;
+        }
+        let Some(snack) = self.snacks.get(next) else {
+            if heaviest < self.best_max {
+                self.best_max = heaviest;
+                self.best.clone_from(&self.assignment);
+            }
+            return;
+        };
+        let mut tried = HashSet::with_capacity(self.loads.len());
+        for carrier in 0..self.loads.len() {
+            let load = self.loads[carrier];
+            // Giving it to a carrier with the same load as one already tried gets the same plans,
+            // just with the carriers swapped around.
+            if !tried.insert(load) {
+                continue;
+            }
+            let loaded = load + snack.calories;
+            if loaded >= self.best_max {
+                continue;
+            }
+            self.loads[carrier] = loaded;
+            self.assignment[next] = carrier;
+            self.assign(next + 1, heaviest.max(loaded));
+            self.loads[carrier] = load;
+        }
+    }
+}
+
+fn max_load(snacks: &[Snack], assignment: &[usize], carriers: usize) -> u64 {
+    let mut loads = vec![0; carriers];
+    for (snack, &carrier) in snacks.iter().zip(assignment) {
+        loads[carrier] += snack.calories;
+    }
+    loads.into_iter().max().unwrap_or(0)
+}
+
+fn build(method: Method, snacks: &[Snack], assignment: &[usize], carriers: usize) -> Plan {
+    let mut plan = Plan {
+        method,
+        carriers: vec![Carrier::default(); carriers],
+        idle: 0,
+    };
+    for (&snack, &carrier) in snacks.iter().zip(assignment) {
+        let carrier = &mut plan.carriers[carrier];
+        carrier.snacks.push(snack);
+        carrier.total += snack.calories;
+    }
+    plan
+}
+
+const USAGE: &str = "usage: day-01 rebalance <carriers> [<path>]";
+
+/// The arguments after `rebalance`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Args {
+    pub carriers: usize,
+    pub source: Source,
+}
+
+impl Args {
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut args = args.into_iter();
+        let carriers = args::positive(&mut args, "number of carriers")?;
+        let source = args::parse_source(args)?;
+        Ok(Self { carriers, source })
+    }
+}
+
+/// Run `day-01 rebalance` with the arguments after `rebalance`, exiting on any error.
+pub fn main(args: impl IntoIterator<Item = String>) {
+    let args = args::or_exit(Args::parse(args), USAGE);
+    let inventory = input::parse_or_exit(&args.source, ElfInventory::parse);
+    let plan = plan(&inventory, args.carriers).expect("there is at least one carrier");
+    print!("{}", plan.to_text());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snacks(calories: &[u64]) -> Vec<Snack> {
+        calories
+            .iter()
+            .enumerate()
+            .map(|(index, &calories)| Snack {
+                elf: 0,
+                index,
+                calories,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_plan() {
+        let inventory = ElfInventory::parse(include_str!("example.txt")).unwrap();
+        let plan = plan(&inventory, 3).unwrap();
+        assert_eq!(plan.method, Method::Exact);
+        // 55 thousand calories can't be split into three lots of 18 thousand.
+        assert_eq!(plan.max(), 19_000);
+        let snacks = plan
+            .carriers
+            .iter()
+            .map(|carrier| carrier.snacks.len())
+            .sum::<usize>();
+        assert_eq!(snacks, 10);
+        for carrier in &plan.carriers {
+            let total = carrier.snacks.iter().map(|snack| snack.calories).sum();
+            assert_eq!(carrier.total, total);
+        }
+
+        assert_eq!(super::plan(&inventory, 1).unwrap().max(), 55_000);
+        let spread = super::plan(&inventory, 20).unwrap();
+        assert_eq!(spread.max(), 10_000);
+        assert_eq!((spread.carriers.len(), spread.idle), (10, 10));
+        assert!(spread
+            .to_text()
+            .contains("\ncarriers 11 to 20 carry nothing\n"));
+        assert!(super::plan(&inventory, 0).is_none());
+    }
+
+    #[test]
+    fn test_many_carriers() {
+        let inventory = ElfInventory::parse("1\n2\n\n3").unwrap();
+        let plan = plan(&inventory, usize::MAX).unwrap();
+        assert_eq!(plan.max(), 3);
+        assert_eq!((plan.carriers.len(), plan.idle), (3, usize::MAX - 3));
+        let plan = super::plan(&ElfInventory::parse("").unwrap(), 2).unwrap();
+        assert_eq!((plan.carriers.len(), plan.idle, plan.max()), (0, 2, 0));
+        assert_eq!(
+            plan.to_text(),
+            "carriers 1 to 2 carry nothing\nheaviest carrier: 0 (the best possible)\n"
+        );
+    }
+
+    #[test]
+    fn test_beats_lpt() {
+        // LPT puts 3 and 3 apart and ends up with 3 + 2 + 2 on one side.
+        let snacks = snacks(&[3, 3, 2, 2, 2]);
+        let lpt = lpt(&snacks, 2);
+        assert_eq!(max_load(&snacks, &lpt, 2), 7);
+        let best = branch_and_bound(&snacks, 2, lpt);
+        assert_eq!(max_load(&snacks, &best, 2), 6);
+    }
+
+    #[test]
+    fn test_large_inputs_use_lpt() {
+        let input = (1..=EXACT_LIMIT as u64 + 1)
+            .map(|calories| calories.to_string())
+            .collect::<Vec<_>>()
+            .join("\n");
+        let inventory = ElfInventory::parse(&input).unwrap();
+        let plan = plan(&inventory, 4).unwrap();
+        assert_eq!(plan.method, Method::Lpt);
+        // 231 calories between four carriers.
+        assert!((58..=58 * 4 / 3).contains(&plan.max()), "{}", plan.max());
+    }
+
+    #[test]
+    fn test_args() {
+        let args = Args::parse(["3".to_string(), "input.txt".to_string()]).unwrap();
+        assert_eq!(args.carriers, 3);
+        assert!(Args::parse(["0".to_string()]).is_err());
+        assert!(Args::parse([]).is_err());
+    }
+}
@@ -0,0 +1,353 @@
+//! `day-01 stats`: how the calories are spread between the elves, rather than just who carries
+//! the most.
+
+use crate::inventory::ElfInventory;
+use aoc_core::{args, args::Args, input};
+use std::{fmt::Write, str::FromStr};
+
+/// The percentiles in the report.
+pub const PERCENTILES: [u8; 6] = [10, 25, 50, 75, 90, 99];
+
+/// The most buckets the histogram of totals is split into.
+const BUCKETS: usize = 10;
+
+/// The longest bar in the text histogram.
+const BAR_WIDTH: usize = 40;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stats {
+    pub elves: usize,
+    pub items: usize,
+    /// Wider than the totals it adds up, so it can't overflow.
+    pub calories: u128,
+    /// The mean and median of the elves' totals.
+    pub mean: f64,
+    pub median: f64,
+    /// The total at each of the [`PERCENTILES`], by nearest rank.
+    pub percentiles: Vec<(u8, u64)>,
+    /// The fewest, the mean, and the most snacks an elf carries. Only the calories get the full
+    /// spread; the snack counts are just summed up like this.
+    pub items_per_elf: (usize, f64, usize),
+    /// The elf carrying the biggest single snack, and its calories.
+    pub largest_item: (usize, u64),
+    pub histogram: Vec<Bucket>,
+}
+
+/// How many elves carry between `from` and `to` calories, both included.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Bucket {
+    pub from: u64,
+    pub to: u64,
+    pub elves: usize,
+}
+
+impl Stats {
+    /// None if there are no elves to say anything about.
+    pub fn new(inventory: &ElfInventory) -> Option<Self> {
+        if inventory.is_empty() {
+            return None;
+        }
+        let elves = inventory.len();
+        let mut totals = inventory.totals().to_vec();
+        totals.sort_unstable();
+        let calories = totals.iter().map(|&total| u128::from(total)).sum::<u128>();
+
+        let middle = elves / 2;
+        let median = if elves.is_multiple_of(2) {
+            f64::midpoint(totals[middle - 1] as f64, totals[middle] as f64)
+        } else {
+            totals[middle] as f64
+        };
+        let percentiles = PERCENTILES
+            .iter()
+            .map(|&percentile| {
+                let rank = (usize::from(percentile) * elves).div_ceil(100).max(1);
+                (percentile, totals[rank - 1])
+            })
+            .collect();
+
+        let counts = (0..elves).map(|elf| inventory.items(elf).len());
+        let items = counts.clone().sum::<usize>();
+        let items_per_elf = (
+            counts.clone().min().unwrap_or(0),
+            items as f64 / elves as f64,
+            counts.max().unwrap_or(0),
+        );
+        let largest_item = (0..elves)
+            .flat_map(|elf| inventory.items(elf).iter().map(move |&item| (elf, item)))
+            // The first elf carrying it, if more than one does.
+            .fold(
+                (0, 0),
+                |largest, item| {
+                    if item.1 > largest.1 {
+                        item
+                    } else {
+                        largest
+                    }
+                },
+            );
+
+        Some(Self {
+            elves,
+            items,
+            calories,
+            mean: calories as f64 / elves as f64,
+            median,
+            percentiles,
+            items_per_elf,
+            largest_item,
+            histogram: histogram(&totals),
+        })
+    }
+
+    /// The report for people, with elves numbered from 1 like in the puzzle.
+    pub fn to_text(&self) -> String {
+        let mut text = String::new();
+        let (fewest, mean_items, most) = self.items_per_elf;
+        let (elf, largest) = self.largest_item;
+        writeln!(text, "elves           {}", self.elves).unwrap();
+        writeln!(text, "snacks          {}", self.items).unwrap();
+        writeln!(text, "calories        {}", self.calories).unwrap();
+        writeln!(text, "mean            {:.1}", self.mean).unwrap();
+        writeln!(text, "median          {}", self.median).unwrap();
+        for (percentile, total) in &self.percentiles {
+            writeln!(text, "p{percentile:<14} {total}").unwrap();
+        }
+        writeln!(
+            text,
+            "snacks per elf  {fewest} to {most}, {mean_items:.1} on average"
+        )
+        .unwrap();
+        writeln!(
+            text,
+            "largest snack   {largest}, carried by elf {}",
+            elf + 1
+        )
+        .unwrap();
+
+        writeln!(text, "\ntotals:").unwrap();
+        let widest = self.histogram.iter().map(|bucket| bucket.elves).max();
+        let digits = self
+            .histogram
+            .last()
+            .map_or(1, |bucket| bucket.to.to_string().len());
+        for bucket in &self.histogram {
+            let bar = match widest {
+                Some(widest) if widest > 0 => (bucket.elves * BAR_WIDTH).div_ceil(widest),
+                _ => 0,
+            };
+            writeln!(
+                text,
+                "{:>digits$} - {:>digits$}  {:<BAR_WIDTH$}  {}",
+                bucket.from,
+                bucket.to,
+                "#".repeat(bar),
+                bucket.elves
+            )
+            .unwrap();
+        }
+        text
+    }
+
+    /// The report as `statistic,value` rows, numbering elves the same way as [`Self::to_text`].
+    pub fn to_csv(&self) -> String {
+        let (fewest, mean_items, most) = self.items_per_elf;
+        let (elf, largest) = self.largest_item;
+        let mut csv = String::from("statistic,value\n");
+        let mut row = |statistic: &str, value: &dyn std::fmt::Display| {
+            writeln!(csv, "{statistic},{value}").unwrap();
+        };
+        row("elves", &self.elves);
+        row("snacks", &self.items);
+        row("calories", &self.calories);
+        row("mean", &self.mean);
+        row("median", &self.median);
+        for (percentile, total) in &self.percentiles {
+            row(&format!("p{percentile}"), total);
+        }
+        row("fewest snacks per elf", &fewest);
+        row("mean snacks per elf", &mean_items);
+        row("most snacks per elf", &most);
+        row("largest snack", &largest);
+        row("elf with the largest snack", &(elf + 1));
+        for bucket in &self.histogram {
+            row(
+                &format!("totals {}-{}", bucket.from, bucket.to),
+                &bucket.elves,
+            );
+        }
+        csv
+    }
+}
+
+/// Sorted `totals` counted into up to [`BUCKETS`] buckets of the same width.
+fn histogram(totals: &[u64]) -> Vec<Bucket> {
+    let (Some(&min), Some(&max)) = (totals.first(), totals.last()) else {
+        return Vec::new();
+    };
+    // Widened, since every u64 at once is one more than the biggest u64.
+    let width = (u128::from(max - min) + 1).div_ceil(BUCKETS.min(totals.len()) as u128) as u64;
+    let buckets = (max - min) / width + 1;
+    let mut histogram = (0..buckets)
+        .map(|bucket| {
+            let from = min + bucket * width;
+            Bucket {
+                from,
+                to: from.saturating_add(width - 1),
+                elves: 0,
+            }
+        })
+        .collect::<Vec<_>>();
+    for total in totals {
+        histogram[((total - min) / width) as usize].elves += 1;
+    }
+    histogram
+}
+
+/// How to print the report.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+pub enum Format {
+    #[default]
+    Text,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!("`{s}` is not a format; expected `text` or `csv`")),
+        }
+    }
+}
+
+const USAGE: &str = "usage: day-01 stats [--format text|csv] [<path>]";
+
+/// Run `day-01 stats` with the arguments after `stats`, exiting on any error.
+pub fn main(args: impl IntoIterator<Item = String>) {
+    let args = args::or_exit(Args::<Format>::parse(args), USAGE);
+    let inventory = input::parse_or_exit(&args.source, ElfInventory::parse);
+    let Some(stats) = Stats::new(&inventory) else {
+        eprintln!("error: there are no elves in the input");
+        std::process::exit(1);
+    };
+    match args.format {
+        Format::Text => print!("{}", stats.to_text()),
+        Format::Csv => print!("{}", stats.to_csv()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Stats {
+        let inventory = ElfInventory::parse(include_str!("example.txt")).unwrap();
+        Stats::new(&inventory).unwrap()
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = example();
+        assert_eq!((stats.elves, stats.items, stats.calories), (5, 10, 55_000));
+        assert_eq!((stats.mean, stats.median), (11_000.0, 10_000.0));
+        assert_eq!(
+            stats.percentiles,
+            [
+                (10, 4_000),
+                (25, 6_000),
+                (50, 10_000),
+                (75, 11_000),
+                (90, 24_000),
+                (99, 24_000)
+            ]
+        );
+        assert_eq!(stats.items_per_elf, (1, 2.0, 3));
+        assert_eq!(stats.largest_item, (4, 10_000));
+        assert_eq!(
+            stats
+                .histogram
+                .iter()
+                .map(|bucket| bucket.elves)
+                .sum::<usize>(),
+            5
+        );
+        assert_eq!(stats.histogram[0].from, 4_000);
+        assert!(stats.histogram.last().unwrap().to >= 24_000);
+
+        let even = ElfInventory::parse("1\n\n2\n\n3\n\n10").unwrap();
+        assert_eq!(Stats::new(&even).unwrap().median, 2.5);
+        assert!(Stats::new(&ElfInventory::parse("").unwrap()).is_none());
+
+        let max = u64::MAX;
+        let huge = ElfInventory::parse(&format!("{max}\n\n{max}")).unwrap();
+        let stats = Stats::new(&huge).unwrap();
+        assert_eq!(stats.median, max as f64);
+        assert_eq!(stats.calories, 2 * u128::from(max));
+    }
+
+    #[test]
+    fn test_histogram() {
+        let buckets = histogram(&[1, 2, 2, 3]);
+        assert_eq!(
+            buckets,
+            [
+                Bucket {
+                    from: 1,
+                    to: 1,
+                    elves: 1
+                },
+                Bucket {
+                    from: 2,
+                    to: 2,
+                    elves: 2
+                },
+                Bucket {
+                    from: 3,
+                    to: 3,
+                    elves: 1
+                },
+            ]
+        );
+        assert_eq!(
+            histogram(&[7]),
+            [Bucket {
+                from: 7,
+                to: 7,
+                elves: 1
+            }]
+        );
+        let widest = histogram(&[0, u64::MAX]);
+        assert_eq!(widest.last().unwrap().to, u64::MAX);
+        assert_eq!(widest.iter().map(|bucket| bucket.elves).sum::<usize>(), 2);
+    }
+
+    #[test]
+    fn test_formats() {
+        let stats = example();
+        let text = stats.to_text();
+        assert!(
+            text.contains("largest snack   10000, carried by elf 5\n"),
+            "{text}"
+        );
+        assert!(text.contains("p50             10000\n"), "{text}");
+        let csv = stats.to_csv();
+        assert!(csv.starts_with("statistic,value\nelves,5\n"), "{csv}");
+        assert!(csv.contains("\nmedian,10000\n"), "{csv}");
+        assert!(
+            csv.lines().all(|line| line.split(',').count() == 2),
+            "{csv}"
+        );
+    }
+
+    #[test]
+    fn test_args() {
+        let args =
+            Args::<Format>::parse(["--format=csv".to_string(), "input.txt".to_string()]).unwrap();
+        assert_eq!(args.format, Format::Csv);
+        assert!(Args::<Format>::parse(["--format".to_string(), "json".to_string()]).is_err());
+    }
+}
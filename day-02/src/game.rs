@@ -0,0 +1,274 @@
+//! Games like Rock Paper Scissors with any odd number of shapes, where which shape beats which and
+//! what each is worth are data rather than code.
+
+use crate::RochambeauOutcome;
+use std::{fmt::Display, sync::OnceLock};
+
+/// A game where every pair of different shapes has a winner, and every shape beats exactly half of
+/// the others, so no shape is better than another. That takes an odd number of shapes.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct CyclicGame {
+    names: Vec<String>,
+    points: Vec<usize>,
+    /// The outcome of shape `a` against shape `b` is at `a * len + b`.
+    outcomes: Vec<RochambeauOutcome>,
+}
+
+/// Why the shapes and rules given don't make a [`CyclicGame`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum GameError {
+    /// There needs to be an odd number of shapes for each to beat half of the rest.
+    EvenShapes(usize),
+    UnknownShape(String),
+    DuplicateShape(String),
+    /// Either neither shape beats the other, or each does.
+    NoWinner(String, String),
+    /// The shape beats the given number of others, rather than half of them.
+    Unbalanced(String, usize),
+}
+
+impl Display for GameError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GameError::EvenShapes(shapes) => {
+                write!(f, "a game needs an odd number of shapes, not {shapes}")
+            }
+            GameError::UnknownShape(name) => write!(f, "there is no shape called `{name}`"),
+            GameError::DuplicateShape(name) => write!(f, "there are two shapes called `{name}`"),
+            GameError::NoWinner(a, b) => {
+                write!(f, "exactly one of `{a}` and `{b}` should beat the other")
+            }
+            GameError::Unbalanced(name, beaten) => {
+                write!(
+                    f,
+                    "`{name}` beats {beaten} shapes rather than half of the others"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for GameError {}
+
+impl CyclicGame {
+    /// A game of `shapes`, each a name and the points for playing it, where each `(a, b)` in
+    /// `beats` says that shape `a` beats shape `b`.
+    pub fn new(shapes: &[(&str, usize)], beats: &[(&str, &str)]) -> Result<Self, GameError> {
+        let len = shapes.len();
+        if len.is_multiple_of(2) {
+            return Err(GameError::EvenShapes(len));
+        }
+        let names = shapes
+            .iter()
+            .map(|(name, _)| name.to_string())
+            .collect::<Vec<_>>();
+        for (index, name) in names.iter().enumerate() {
+            if names[..index].contains(name) {
+                return Err(GameError::DuplicateShape(name.clone()));
+            }
+        }
+        let find = |name: &str| {
+            names
+                .iter()
+                .position(|shape| shape == name)
+                .ok_or_else(|| GameError::UnknownShape(name.to_string()))
+        };
+
+        let mut wins = vec![false; len * len];
+        for (winner, loser) in beats {
+            wins[find(winner)? * len + find(loser)?] = true;
+        }
+        let mut outcomes = vec![RochambeauOutcome::Tie; len * len];
+        for a in 0..len {
+            for b in (0..len).filter(|&b| b != a) {
+                outcomes[a * len + b] = match (wins[a * len + b], wins[b * len + a]) {
+                    (true, false) => RochambeauOutcome::Win,
+                    (false, true) => RochambeauOutcome::Loss,
+                    _ => return Err(GameError::NoWinner(names[a].clone(), names[b].clone())),
+                };
+            }
+            let beaten = wins[a * len..(a + 1) * len]
+                .iter()
+                .filter(|&&win| win)
+                .count();
+            if beaten != len / 2 {
+                return Err(GameError::Unbalanced(names[a].clone(), beaten));
+            }
+        }
+
+        Ok(Self {
+            names,
+            points: shapes.iter().map(|&(_, points)| points).collect(),
+            outcomes,
+        })
+    }
+
+    /// A game of `shapes` in the order they go around the cycle, where each beats the half of the
+    /// others that come just before it, wrapping around.
+    pub fn cyclic(shapes: &[(&str, usize)]) -> Result<Self, GameError> {
+        let len = shapes.len();
+        let beats = (0..len)
+            .flat_map(|winner| {
+                (1..=len / 2).map(move |behind| (winner, (winner + len - behind) % len))
+            })
+            .map(|(winner, loser)| (shapes[winner].0, shapes[loser].0))
+            .collect::<Vec<_>>();
+        Self::new(shapes, &beats)
+    }
+
+    /// Rock, Paper and Scissors, worth 1, 2 and 3 points.
+    pub fn classic() -> &'static Self {
+        static CLASSIC: OnceLock<CyclicGame> = OnceLock::new();
+        CLASSIC.get_or_init(|| {
+            Self::cyclic(&[("Rock", 1), ("Paper", 2), ("Scissors", 3)])
+                .expect("the classic game is a game")
+        })
+    }
+
+    /// Rock Paper Scissors Lizard Spock, worth 1 to 5 points in that order.
+    pub fn rock_paper_scissors_lizard_spock() -> Self {
+        let shapes = [
+            ("Rock", 1),
+            ("Paper", 2),
+            ("Scissors", 3),
+            ("Lizard", 4),
+            ("Spock", 5),
+        ];
+        let beats = [
+            ("Scissors", "Paper"),
+            ("Paper", "Rock"),
+            ("Rock", "Lizard"),
+            ("Lizard", "Spock"),
+            ("Spock", "Scissors"),
+            ("Scissors", "Lizard"),
+            ("Lizard", "Paper"),
+            ("Paper", "Spock"),
+            ("Spock", "Rock"),
+            ("Rock", "Scissors"),
+        ];
+        Self::new(&shapes, &beats).expect("Rock Paper Scissors Lizard Spock is a game")
+    }
+
+    /// How many shapes there are. Shapes are numbered from 0 in the order they were given.
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    pub fn name(&self, shape: usize) -> &str {
+        &self.names[shape]
+    }
+
+    /// The shape called `name`, if there is one.
+    pub fn shape(&self, name: &str) -> Option<usize> {
+        self.names.iter().position(|shape| shape == name)
+    }
+
+    /// The points for playing `shape`.
+    pub fn points(&self, shape: usize) -> usize {
+        self.points[shape]
+    }
+
+    /// The outcome of playing `shape` against `other`, from the point of view of `shape`.
+    pub fn play(&self, shape: usize, other: usize) -> RochambeauOutcome {
+        self.outcomes[shape * self.len() + other]
+    }
+
+    /// The shapes that end in `outcome` when played against `other`.
+    pub fn responses(
+        &self,
+        other: usize,
+        outcome: RochambeauOutcome,
+    ) -> impl Iterator<Item = usize> + '_ {
+        (0..self.len()).filter(move |&shape| self.play(shape, other) == outcome)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classic() {
+        let game = CyclicGame::classic();
+        let [rock, paper, scissors] =
+            ["Rock", "Paper", "Scissors"].map(|name| game.shape(name).unwrap());
+        assert_eq!(game.play(rock, scissors), RochambeauOutcome::Win);
+        assert_eq!(game.play(rock, paper), RochambeauOutcome::Loss);
+        assert_eq!(game.play(paper, paper), RochambeauOutcome::Tie);
+        assert_eq!(game.points(scissors), 3);
+        assert_eq!(
+            game.responses(rock, RochambeauOutcome::Win)
+                .collect::<Vec<_>>(),
+            [paper]
+        );
+    }
+
+    #[test]
+    fn test_rock_paper_scissors_lizard_spock() {
+        let game = CyclicGame::rock_paper_scissors_lizard_spock();
+        let shape = |name| game.shape(name).unwrap();
+        assert_eq!(
+            game.play(shape("Spock"), shape("Rock")),
+            RochambeauOutcome::Win
+        );
+        assert_eq!(
+            game.play(shape("Lizard"), shape("Rock")),
+            RochambeauOutcome::Loss
+        );
+        for shape in 0..game.len() {
+            assert_eq!(game.responses(shape, RochambeauOutcome::Win).count(), 2);
+        }
+
+        // The same game, laid out around its cycle.
+        let cyclic = CyclicGame::cyclic(&[
+            ("Rock", 1),
+            ("Spock", 5),
+            ("Paper", 2),
+            ("Lizard", 4),
+            ("Scissors", 3),
+        ])
+        .unwrap();
+        for a in 0..game.len() {
+            for b in 0..game.len() {
+                let [ca, cb] = [a, b].map(|shape| cyclic.shape(game.name(shape)).unwrap());
+                assert_eq!(game.play(a, b), cyclic.play(ca, cb));
+            }
+        }
+    }
+
+    #[test]
+    fn test_errors() {
+        let shapes = [("Rock", 1), ("Paper", 2), ("Scissors", 3)];
+        assert_eq!(
+            CyclicGame::new(&shapes[..2], &[("Paper", "Rock")]),
+            Err(GameError::EvenShapes(2))
+        );
+        assert_eq!(
+            CyclicGame::new(&shapes, &[("Paper", "Stone")]),
+            Err(GameError::UnknownShape(String::from("Stone")))
+        );
+        assert_eq!(
+            CyclicGame::new(&shapes, &[("Paper", "Rock"), ("Scissors", "Paper")]),
+            Err(GameError::NoWinner(
+                String::from("Rock"),
+                String::from("Scissors")
+            ))
+        );
+        assert!(matches!(
+            CyclicGame::new(
+                &shapes,
+                &[
+                    ("Paper", "Rock"),
+                    ("Scissors", "Paper"),
+                    ("Scissors", "Rock")
+                ]
+            ),
+            Err(GameError::Unbalanced(..))
+        ));
+        assert!(CyclicGame::cyclic(&[("Rock", 1)]).is_ok());
+    }
+}
@@ -0,0 +1,31 @@
+//! Random strategy guides.
+
+use aoc_core::random::Rng;
+
+/// `size` rounds, each any of the nine combinations.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size.max(1) {
+        input.push(*rng.choose(&['A', 'B', 'C']));
+        input.push(' ');
+        input.push(*rng.choose(&['X', 'Y', 'Z']));
+        input.push('\n');
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day02;
+    use aoc_core::Day;
+
+    #[test]
+    fn test_parses() {
+        for seed in 0..20 {
+            let input = input(&mut Rng::new(seed), 10);
+            let parsed = Day02.parse(&input).unwrap();
+            assert_eq!(parsed.rounds().len(), 10);
+        }
+    }
+}
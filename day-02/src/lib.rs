@@ -0,0 +1,304 @@
+//! Day 2: Rock Paper Scissors.
+
+pub mod game;
+pub mod generate;
+pub mod optimize;
+pub mod strategy;
+
+use aoc_core::{random::Rng, Day, ParseError};
+use game::CyclicGame;
+use std::str::FromStr;
+
+/// Rock Paper Scissors, scored according to an encrypted strategy guide.
+pub struct Day02;
+
+impl Day for Day02 {
+    const DAY: u8 = 2;
+    type Input<'a> = Input;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        input.parse()
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>) -> Self::Part1 {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>) -> Self::Part2 {
+        part_2(input)
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+}
+
+/// The total score if X, Y and Z are the shapes to play.
+pub fn part_1(input: &Input) -> usize {
+    input.points(&PartOneInterpretation)
+}
+
+/// The total score if X, Y and Z are the outcomes the round needs to end in.
+pub fn part_2(input: &Input) -> usize {
+    input.points(&PartTwoInterpretation)
+}
+
+/// The strategy guide: one round per line.
+#[derive(Clone, Debug)]
+pub struct Input {
+    rounds: Vec<Round>,
+}
+
+impl Input {
+    pub fn rounds(&self) -> &[Round] {
+        &self.rounds
+    }
+
+    /// The total score over every round, reading the guide with `interpretation`.
+    pub fn points(&self, interpretation: &impl Interpretation) -> usize {
+        let mut sum = 0;
+        for round in &self.rounds {
+            sum += round.points(interpretation);
+        }
+        sum
+    }
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut rounds = Vec::new();
+        for line in s.lines().filter(|line| !line.is_empty()) {
+            let round = line.parse::<Round>().map_err(|err| err.within(line))?;
+            rounds.push(round);
+        }
+        Ok(Input { rounds })
+    }
+}
+
+/// A line of the strategy guide: what the opponent will play, and the recommendation in the second
+/// column, whatever it turns out to mean.
+#[derive(Copy, Clone, Debug)]
+pub struct Round {
+    opponent: Opponent,
+    recommendation: Recommendation,
+}
+
+impl Round {
+    pub fn new(opponent: Opponent, recommendation: Recommendation) -> Self {
+        Self {
+            opponent,
+            recommendation,
+        }
+    }
+
+    pub fn opponent(&self) -> Opponent {
+        self.opponent
+    }
+
+    pub fn recommendation(&self) -> Recommendation {
+        self.recommendation
+    }
+
+    /// The score for this round: the points for the shape played plus the points for the outcome.
+    pub fn points(&self, interpretation: &impl Interpretation) -> usize {
+        let opponent = interpretation.rochambeau_for_opponent(self);
+        let recommendation = interpretation.rochambeau_for_recommendation(self);
+        let outcome = recommendation.play(&opponent);
+
+        recommendation.points() + outcome.points()
+    }
+}
+
+impl FromStr for Round {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (opponent, recommendation) = s
+            .split_once(' ')
+            .ok_or_else(|| ParseError::expected("two columns separated by a space", s))?;
+        let opponent = opponent.parse()?;
+        let recommendation = recommendation.parse()?;
+        Ok(Round {
+            opponent,
+            recommendation,
+        })
+    }
+}
+
+/// The first column of the guide.
+#[derive(Copy, Clone, Debug)]
+pub enum Opponent {
+    A,
+    B,
+    C,
+}
+
+impl FromStr for Opponent {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "A" => Ok(Self::A),
+            "B" => Ok(Self::B),
+            "C" => Ok(Self::C),
+            _ => Err(ParseError::expected("`A`, `B` or `C`", s)),
+        }
+    }
+}
+
+/// The second column of the guide.
+#[derive(Copy, Clone, Debug)]
+pub enum Recommendation {
+    X,
+    Y,
+    Z,
+}
+
+impl FromStr for Recommendation {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "X" => Ok(Self::X),
+            "Y" => Ok(Self::Y),
+            "Z" => Ok(Self::Z),
+            _ => Err(ParseError::expected("`X`, `Y` or `Z`", s)),
+        }
+    }
+}
+
+/// A shape in [`CyclicGame::classic`], where the game's rules and points come from.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Rochambeau {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+impl Rochambeau {
+    pub const ALL: [Rochambeau; 3] = [Rochambeau::Rock, Rochambeau::Paper, Rochambeau::Scissors];
+
+    /// The shape's number in [`CyclicGame::classic`].
+    pub fn index(&self) -> usize {
+        *self as usize
+    }
+
+    pub fn from_index(index: usize) -> Self {
+        Self::ALL[index]
+    }
+
+    /// The outcome of playing `self` against `other`, from the point of view of `self`.
+    pub fn play(&self, other: &Rochambeau) -> RochambeauOutcome {
+        CyclicGame::classic().play(self.index(), other.index())
+    }
+
+    /// The points for playing this shape.
+    pub fn points(&self) -> usize {
+        CyclicGame::classic().points(self.index())
+    }
+
+    /// The shape to play against `other` for the round to end in `outcome`.
+    pub fn for_outcome(other: &Rochambeau, outcome: RochambeauOutcome) -> Rochambeau {
+        let mut responses = CyclicGame::classic().responses(other.index(), outcome);
+        Self::from_index(responses.next().expect("every outcome is possible"))
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum RochambeauOutcome {
+    Win,
+    Tie,
+    Loss,
+}
+
+impl RochambeauOutcome {
+    /// The points for ending a round this way.
+    pub fn points(&self) -> usize {
+        match self {
+            RochambeauOutcome::Win => 6,
+            RochambeauOutcome::Tie => 3,
+            RochambeauOutcome::Loss => 0,
+        }
+    }
+}
+
+/// A way of reading the strategy guide: which shape each player ends up playing in a round.
+pub trait Interpretation {
+    fn rochambeau_for_opponent(&self, round: &Round) -> Rochambeau;
+    fn rochambeau_for_recommendation(&self, round: &Round) -> Rochambeau;
+}
+
+/// A, B and C are Rock, Paper and Scissors, and so are X, Y and Z.
+pub struct PartOneInterpretation;
+
+impl Interpretation for PartOneInterpretation {
+    fn rochambeau_for_opponent(&self, round: &Round) -> Rochambeau {
+        match round.opponent {
+            Opponent::A => Rochambeau::Rock,
+            Opponent::B => Rochambeau::Paper,
+            Opponent::C => Rochambeau::Scissors,
+        }
+    }
+
+    fn rochambeau_for_recommendation(&self, round: &Round) -> Rochambeau {
+        match round.recommendation {
+            Recommendation::X => Rochambeau::Rock,
+            Recommendation::Y => Rochambeau::Paper,
+            Recommendation::Z => Rochambeau::Scissors,
+        }
+    }
+}
+
+/// A, B and C are Rock, Paper and Scissors, and X, Y and Z say to lose, draw or win.
+pub struct PartTwoInterpretation;
+
+impl Interpretation for PartTwoInterpretation {
+    fn rochambeau_for_opponent(&self, round: &Round) -> Rochambeau {
+        match round.opponent {
+            Opponent::A => Rochambeau::Rock,
+            Opponent::B => Rochambeau::Paper,
+            Opponent::C => Rochambeau::Scissors,
+        }
+    }
+
+    fn rochambeau_for_recommendation(&self, round: &Round) -> Rochambeau {
+        let expected_outcome = match round.recommendation {
+            Recommendation::X => RochambeauOutcome::Loss,
+            Recommendation::Y => RochambeauOutcome::Tie,
+            Recommendation::Z => RochambeauOutcome::Win,
+        };
+        let opponent = self.rochambeau_for_opponent(round);
+        Rochambeau::for_outcome(&opponent, expected_outcome)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rochambeau() {
+        for shape in Rochambeau::ALL {
+            assert_eq!(Rochambeau::from_index(shape.index()), shape);
+            assert_eq!(shape.play(&shape), RochambeauOutcome::Tie);
+        }
+        assert_eq!(
+            Rochambeau::Rock.play(&Rochambeau::Scissors),
+            RochambeauOutcome::Win
+        );
+        assert_eq!(
+            Rochambeau::for_outcome(&Rochambeau::Scissors, RochambeauOutcome::Loss),
+            Rochambeau::Paper
+        );
+        assert_eq!(Rochambeau::Scissors.points(), 3);
+    }
+
+    aoc_core::example_tests! {
+        test_part_1: Day02, "example.txt", part_1 => 15;
+        test_part_2: Day02, "example.txt", part_2 => 12;
+    }
+}
@@ -0,0 +1,209 @@
+//! `day-02 interpretations`: the guide never says what X, Y and Z mean, so try every reading of
+//! them and see how each would score.
+
+use crate::{
+    Input, Interpretation, PartOneInterpretation, Recommendation, Rochambeau, RochambeauOutcome,
+    Round,
+};
+use aoc_core::{args, input};
+use std::fmt::{Display, Write};
+
+/// X, Y and Z are shapes to play, each a different one.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct ShapeInterpretation {
+    pub shapes: [Rochambeau; 3],
+}
+
+/// X, Y and Z are outcomes to aim for, each a different one.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct OutcomeInterpretation {
+    pub outcomes: [RochambeauOutcome; 3],
+}
+
+/// A, B and C are always Rock, Paper and Scissors, as the elf explained that much.
+impl Interpretation for ShapeInterpretation {
+    fn rochambeau_for_opponent(&self, round: &Round) -> Rochambeau {
+        PartOneInterpretation.rochambeau_for_opponent(round)
+    }
+
+    fn rochambeau_for_recommendation(&self, round: &Round) -> Rochambeau {
+        self.shapes[column(round.recommendation())]
+    }
+}
+
+impl Interpretation for OutcomeInterpretation {
+    fn rochambeau_for_opponent(&self, round: &Round) -> Rochambeau {
+        PartOneInterpretation.rochambeau_for_opponent(round)
+    }
+
+    fn rochambeau_for_recommendation(&self, round: &Round) -> Rochambeau {
+        let opponent = self.rochambeau_for_opponent(round);
+        Rochambeau::for_outcome(&opponent, self.outcomes[column(round.recommendation())])
+    }
+}
+
+fn column(recommendation: Recommendation) -> usize {
+    match recommendation {
+        Recommendation::X => 0,
+        Recommendation::Y => 1,
+        Recommendation::Z => 2,
+    }
+}
+
+impl Display for ShapeInterpretation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let [x, y, z] = self.shapes;
+        write!(f, "play X={x:?} Y={y:?} Z={z:?}")
+    }
+}
+
+impl Display for OutcomeInterpretation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let [x, y, z] = self.outcomes.map(|outcome| match outcome {
+            RochambeauOutcome::Win => "win",
+            RochambeauOutcome::Tie => "draw",
+            RochambeauOutcome::Loss => "lose",
+        });
+        write!(f, "aim to X={x} Y={y} Z={z}")
+    }
+}
+
+/// Either kind of reading, so that they can be ranked together.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Reading {
+    Shapes(ShapeInterpretation),
+    Outcomes(OutcomeInterpretation),
+}
+
+impl Interpretation for Reading {
+    fn rochambeau_for_opponent(&self, round: &Round) -> Rochambeau {
+        match self {
+            Reading::Shapes(shapes) => shapes.rochambeau_for_opponent(round),
+            Reading::Outcomes(outcomes) => outcomes.rochambeau_for_opponent(round),
+        }
+    }
+
+    fn rochambeau_for_recommendation(&self, round: &Round) -> Rochambeau {
+        match self {
+            Reading::Shapes(shapes) => shapes.rochambeau_for_recommendation(round),
+            Reading::Outcomes(outcomes) => outcomes.rochambeau_for_recommendation(round),
+        }
+    }
+}
+
+impl Display for Reading {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Reading::Shapes(shapes) => shapes.fmt(f),
+            Reading::Outcomes(outcomes) => outcomes.fmt(f),
+        }
+    }
+}
+
+/// The orders three things can be put in.
+fn permutations<T: Copy>([a, b, c]: [T; 3]) -> [[T; 3]; 6] {
+    [
+        [a, b, c],
+        [a, c, b],
+        [b, a, c],
+        [b, c, a],
+        [c, a, b],
+        [c, b, a],
+    ]
+}
+
+/// Every way of reading X, Y and Z: the six ways they could be shapes, then the six ways they
+/// could be outcomes. The readings parts 1 and 2 use come first in each.
+pub fn readings() -> Vec<Reading> {
+    let shapes =
+        permutations(Rochambeau::ALL).map(|shapes| Reading::Shapes(ShapeInterpretation { shapes }));
+    let outcomes = permutations([
+        RochambeauOutcome::Loss,
+        RochambeauOutcome::Tie,
+        RochambeauOutcome::Win,
+    ])
+    .map(|outcomes| Reading::Outcomes(OutcomeInterpretation { outcomes }));
+    shapes.into_iter().chain(outcomes).collect()
+}
+
+/// Every reading with its total score, best first. Readings that score the same stay in the order
+/// [`readings`] gives them.
+pub fn rank(input: &Input) -> Vec<(Reading, usize)> {
+    let mut ranked = readings()
+        .into_iter()
+        .map(|reading| (reading, input.points(&reading)))
+        .collect::<Vec<_>>();
+    ranked.sort_by(|(_, a), (_, b)| b.cmp(a));
+    ranked
+}
+
+/// The ranking as a table, with the readings from the puzzle marked.
+pub fn table(ranked: &[(Reading, usize)]) -> String {
+    let [part_1, ..] = permutations(Rochambeau::ALL);
+    let part_2 = [
+        RochambeauOutcome::Loss,
+        RochambeauOutcome::Tie,
+        RochambeauOutcome::Win,
+    ];
+    let digits = ranked
+        .iter()
+        .map(|(_, points)| points.to_string().len())
+        .max()
+        .unwrap_or(1)
+        .max("score".len());
+    let mut table = format!("rank  {:>digits$}  reading\n", "score");
+    for (rank, (reading, points)) in ranked.iter().enumerate() {
+        let note = match reading {
+            Reading::Shapes(shapes) if shapes.shapes == part_1 => "  (part 1)",
+            Reading::Outcomes(outcomes) if outcomes.outcomes == part_2 => "  (part 2)",
+            _ => "",
+        };
+        let line = format!("{:>4}  {points:>digits$}  {reading:<34}{note}", rank + 1);
+        writeln!(table, "{}", line.trim_end()).unwrap();
+    }
+    table
+}
+
+const USAGE: &str = "usage: day-02 interpretations [<path>]";
+
+/// Run `day-02 interpretations` with the arguments after `interpretations`, exiting on any error.
+pub fn main(args: impl IntoIterator<Item = String>) {
+    let source = args::or_exit(args::parse_source(args), USAGE);
+    let input = input::parse_or_exit(&source, str::parse::<Input>);
+    print!("{}", table(&rank(&input)));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{part_1, part_2, PartTwoInterpretation};
+
+    #[test]
+    fn test_readings() {
+        let readings = readings();
+        assert_eq!(readings.len(), 12);
+        let input = include_str!("example.txt").parse::<Input>().unwrap();
+        assert_eq!(input.points(&readings[0]), part_1(&input));
+        assert_eq!(input.points(&readings[6]), part_2(&input));
+        for round in input.rounds() {
+            assert_eq!(
+                readings[6].rochambeau_for_recommendation(round),
+                PartTwoInterpretation.rochambeau_for_recommendation(round)
+            );
+        }
+    }
+
+    #[test]
+    fn test_rank() {
+        let input = include_str!("example.txt").parse::<Input>().unwrap();
+        let ranked = rank(&input);
+        assert!(ranked.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+        // Playing X=Scissors Y=Paper Z=Rock wins every round of the example.
+        assert_eq!(ranked[0].1, 3 * 6 + 2 + 3 + 1);
+
+        let table = table(&ranked);
+        assert_eq!(table.lines().count(), 13);
+        assert!(table.contains("play X=Rock Y=Paper Z=Scissors"), "{table}");
+        assert_eq!(table.matches("(part ").count(), 2, "{table}");
+    }
+}
@@ -0,0 +1,312 @@
+//! Mixed strategies: playing shapes at random with some probabilities, rather than what the guide
+//! says, scored the same way the rounds are.
+
+use crate::{Input, Interpretation, PartOneInterpretation, Rochambeau};
+
+/// How likely each of Rock, Paper and Scissors is to be played.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Distribution {
+    probabilities: [f64; 3],
+}
+
+impl Distribution {
+    /// Weights for Rock, Paper and Scissors, scaled to add up to 1. None unless they're all
+    /// finite, none is negative, and at least one isn't 0.
+    pub fn new(weights: [f64; 3]) -> Option<Self> {
+        let total = weights.iter().sum::<f64>();
+        let valid = weights
+            .iter()
+            .all(|weight| weight.is_finite() && *weight >= 0.0);
+        (valid && total > 0.0).then(|| Self {
+            probabilities: weights.map(|weight| weight / total),
+        })
+    }
+
+    /// Every shape as likely as the others.
+    pub fn uniform() -> Self {
+        Self {
+            probabilities: [1.0 / 3.0; 3],
+        }
+    }
+
+    /// How often the opponent plays each shape in the guide, going by the first column the way
+    /// both parts read it. Uniform if there are no rounds to go by.
+    pub fn estimate(input: &Input) -> Self {
+        let mut counts = [0.0; 3];
+        for round in input.rounds() {
+            counts[PartOneInterpretation.rochambeau_for_opponent(round).index()] += 1.0;
+        }
+        Self::new(counts).unwrap_or_else(Self::uniform)
+    }
+
+    pub fn probability(&self, shape: Rochambeau) -> f64 {
+        self.probabilities[shape.index()]
+    }
+
+    /// The probabilities of Rock, Paper and Scissors, in that order.
+    pub fn probabilities(&self) -> [f64; 3] {
+        self.probabilities
+    }
+}
+
+/// The score for playing `shape` against `opponent`, as [`crate::Round::points`] scores it.
+pub fn points(shape: Rochambeau, opponent: Rochambeau) -> usize {
+    shape.points() + shape.play(&opponent).points()
+}
+
+/// The score to expect on average from playing `response` against an opponent who plays by
+/// `opponent`.
+pub fn expected_points(response: Rochambeau, opponent: &Distribution) -> f64 {
+    Rochambeau::ALL
+        .iter()
+        .map(|&shape| opponent.probability(shape) * points(response, shape) as f64)
+        .sum()
+}
+
+/// Each shape, with the score to expect from always playing it against `opponent`.
+pub fn expected_values(opponent: &Distribution) -> [(Rochambeau, f64); 3] {
+    Rochambeau::ALL.map(|shape| (shape, expected_points(shape, opponent)))
+}
+
+/// The shape to always play against `opponent` to score the most on average. On a tie, the
+/// cheaper shape.
+pub fn best_response(opponent: &Distribution) -> Rochambeau {
+    expected_values(opponent)
+        .into_iter()
+        .fold(
+            None,
+            |best: Option<(Rochambeau, f64)>, (shape, value)| match best {
+                Some((_, best_value)) if best_value >= value => best,
+                _ => Some((shape, value)),
+            },
+        )
+        .map(|(shape, _)| shape)
+        .expect("there are shapes")
+}
+
+/// A Nash equilibrium of the game, taking the opponent to be out to keep our score down. Neither
+/// side can do better by changing their strategy on their own.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Equilibrium {
+    pub strategy: Distribution,
+    pub opponent: Distribution,
+    /// Our expected score per round when both play their equilibrium strategy.
+    pub value: f64,
+}
+
+/// The equilibrium of the scoring table, found by trying each set of shapes each side might mix
+/// between and keeping the first where neither side has a better shape to switch to.
+pub fn equilibrium() -> Equilibrium {
+    let table =
+        Rochambeau::ALL.map(|shape| Rochambeau::ALL.map(|other| points(shape, other) as f64));
+    let (strategy, opponent, value) = solve_zero_sum(&table).expect("every finite game has one");
+    Equilibrium {
+        strategy: Distribution::new(strategy).expect("probabilities are a distribution"),
+        opponent: Distribution::new(opponent).expect("probabilities are a distribution"),
+        value,
+    }
+}
+
+const EPSILON: f64 = 1e-9;
+
+/// Our mixed strategy, the opponent's, and the value of a zero-sum game where `table[row][column]`
+/// is what we score playing `row` against `column`, and the opponent loses that much.
+fn solve_zero_sum(table: &[[f64; 3]; 3]) -> Option<([f64; 3], [f64; 3], f64)> {
+    // Nonempty subsets of the shapes, as bit masks.
+    for size in 1..=3 {
+        let supports = (1u8..8).filter(|support| support.count_ones() == size);
+        for rows in supports.clone() {
+            for columns in supports.clone() {
+                if let Some(solution) = try_supports(table, rows, columns) {
+                    return Some(solution);
+                }
+            }
+        }
+    }
+    None
+}
+
+/// The equilibrium where we mix between `rows` and the opponent between `columns`, if there is one.
+fn try_supports(table: &[[f64; 3]; 3], rows: u8, columns: u8) -> Option<([f64; 3], [f64; 3], f64)> {
+    let rows = (0..3)
+        .filter(|row| rows & (1 << row) != 0)
+        .collect::<Vec<_>>();
+    let columns = (0..3)
+        .filter(|column| columns & (1 << column) != 0)
+        .collect::<Vec<_>>();
+    // Our strategy leaves the opponent indifferent between their columns, and theirs leaves us
+    // indifferent between our rows.
+    let ours = indifferent(&columns, &rows, |column, row| table[row][column])?;
+    let theirs = indifferent(&rows, &columns, |row, column| table[row][column])?;
+    let value = *ours.last()?;
+
+    let mut strategy = [0.0; 3];
+    for (&row, &probability) in rows.iter().zip(&ours) {
+        strategy[row] = probability;
+    }
+    let mut opponent = [0.0; 3];
+    for (&column, &probability) in columns.iter().zip(&theirs) {
+        opponent[column] = probability;
+    }
+    if strategy.iter().chain(&opponent).any(|&p| p < -EPSILON) {
+        return None;
+    }
+    // Neither side would rather play a shape they're leaving out.
+    let row_value = |row: usize| {
+        (0..3)
+            .map(|column| table[row][column] * opponent[column])
+            .sum::<f64>()
+    };
+    let column_value = |column: usize| {
+        (0..3)
+            .map(|row| table[row][column] * strategy[row])
+            .sum::<f64>()
+    };
+    if (0..3).any(|row| row_value(row) > value + EPSILON)
+        || (0..3).any(|column| column_value(column) < value - EPSILON)
+    {
+        return None;
+    }
+    Some((
+        strategy.map(|p| p.max(0.0)),
+        opponent.map(|p| p.max(0.0)),
+        value,
+    ))
+}
+
+/// Probabilities over `mixed` that give every one of `against` the same payoff, followed by that
+/// payoff, where `payoff(against, mixed)` is the payoff of each pair. None unless there's exactly
+/// one answer.
+fn indifferent(
+    against: &[usize],
+    mixed: &[usize],
+    payoff: impl Fn(usize, usize) -> f64,
+) -> Option<Vec<f64>> {
+    if against.len() != mixed.len() {
+        return None;
+    }
+    // Unknowns: a probability for each of `mixed`, then the payoff.
+    let n = mixed.len() + 1;
+    let mut equations = Vec::with_capacity(n);
+    for &other in against {
+        let mut row = mixed
+            .iter()
+            .map(|&shape| payoff(other, shape))
+            .collect::<Vec<_>>();
+        row.extend([-1.0, 0.0]);
+        equations.push(row);
+    }
+    let mut total = vec![1.0; mixed.len()];
+    total.extend([0.0, 1.0]);
+    equations.push(total);
+    solve(equations)
+}
+
+/// Gaussian elimination on an augmented matrix, `n` rows by `n + 1` columns.
+fn solve(mut matrix: Vec<Vec<f64>>) -> Option<Vec<f64>> {
+    let n = matrix.len();
+    for column in 0..n {
+        let pivot = (column..n)
+            .max_by(|&a, &b| matrix[a][column].abs().total_cmp(&matrix[b][column].abs()))?;
+        if matrix[pivot][column].abs() < EPSILON {
+            return None;
+        }
+        matrix.swap(column, pivot);
+        let pivot = matrix[column].clone();
+        for row in (0..n).filter(|&row| row != column) {
+            let factor = matrix[row][column] / pivot[column];
+            for (value, pivot) in matrix[row][column..].iter_mut().zip(&pivot[column..]) {
+                *value -= factor * pivot;
+            }
+        }
+    }
+    Some(
+        (0..n)
+            .map(|row| matrix[row][n] / matrix[row][row])
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use Rochambeau::*;
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-9, "{a} is not {b}");
+    }
+
+    #[test]
+    fn test_expected_points() {
+        let rock = Distribution::new([1.0, 0.0, 0.0]).unwrap();
+        assert_close(expected_points(Paper, &rock), 8.0);
+        assert_eq!(best_response(&rock), Paper);
+
+        let even = Distribution::uniform();
+        for ((_, value), expected) in expected_values(&even).into_iter().zip([4.0, 5.0, 6.0]) {
+            assert_close(value, expected);
+        }
+        assert_eq!(best_response(&even), Scissors);
+
+        assert!(Distribution::new([0.0; 3]).is_none());
+        assert!(Distribution::new([1.0, -1.0, 1.0]).is_none());
+        assert_eq!(
+            Distribution::new([2.0, 1.0, 1.0]).unwrap().probabilities(),
+            [0.5, 0.25, 0.25]
+        );
+    }
+
+    #[test]
+    fn test_equilibrium() {
+        let equilibrium = equilibrium();
+        assert_close(equilibrium.value, 5.0);
+        for (probability, expected) in equilibrium
+            .strategy
+            .probabilities()
+            .into_iter()
+            .zip([1.0 / 3.0; 3])
+        {
+            assert_close(probability, expected);
+        }
+        for (probability, expected) in
+            equilibrium
+                .opponent
+                .probabilities()
+                .into_iter()
+                .zip([4.0 / 9.0, 1.0 / 9.0, 4.0 / 9.0])
+        {
+            assert_close(probability, expected);
+        }
+        // Against the opponent's equilibrium, every shape scores the same.
+        for (_, value) in expected_values(&equilibrium.opponent) {
+            assert_close(value, 5.0);
+        }
+    }
+
+    #[test]
+    fn test_pure_equilibrium() {
+        // The first row and column are best for either side whatever the other does.
+        let table = [[3.0, 5.0, 4.0], [1.0, 2.0, 0.0], [2.0, 6.0, 1.0]];
+        let (strategy, opponent, value) = solve_zero_sum(&table).unwrap();
+        assert_eq!(
+            (strategy, opponent, value),
+            ([1.0, 0.0, 0.0], [1.0, 0.0, 0.0], 3.0)
+        );
+    }
+
+    #[test]
+    fn test_estimate() {
+        let input = include_str!("example.txt").parse::<Input>().unwrap();
+        let estimate = Distribution::estimate(&input);
+        for shape in Rochambeau::ALL {
+            assert_close(estimate.probability(shape), 1.0 / 3.0);
+        }
+        let input = "A X\nA Y\nC Z\nA Z\n".parse::<Input>().unwrap();
+        assert_eq!(
+            Distribution::estimate(&input).probabilities(),
+            [0.75, 0.0, 0.25]
+        );
+        let empty = "".parse::<Input>().unwrap();
+        assert_eq!(Distribution::estimate(&empty), Distribution::uniform());
+    }
+}
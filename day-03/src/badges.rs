@@ -0,0 +1,203 @@
+//! `day-03 badges`: the badge of every group of elves, for groups of any size, with what went
+//! wrong for the groups that don't have exactly one.
+
+use crate::{item_set::ItemSet, parse_input, Item, Rucksack};
+use aoc_core::{
+    args,
+    input::{self, Source},
+};
+use std::fmt::{Display, Write};
+
+/// Why a group doesn't have a badge.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum BadgeError {
+    /// No item type is in every rucksack of the group.
+    NoBadge,
+    /// More than one item type is in every rucksack of the group.
+    SeveralBadges(ItemSet),
+    /// The rucksacks ran out partway through the last group, which only has this many.
+    IncompleteGroup(usize),
+}
+
+impl Display for BadgeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BadgeError::NoBadge => write!(f, "no item type is in every rucksack"),
+            BadgeError::SeveralBadges(items) => {
+                let items = items.iter().map(|item| item.as_char()).collect::<String>();
+                write!(f, "the item types `{items}` are all in every rucksack")
+            }
+            BadgeError::IncompleteGroup(1) => write!(f, "the last group only has 1 rucksack"),
+            BadgeError::IncompleteGroup(rucksacks) => {
+                write!(f, "the last group only has {rucksacks} rucksacks")
+            }
+        }
+    }
+}
+
+impl std::error::Error for BadgeError {}
+
+/// What one group of elves has in common.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct GroupReport {
+    /// Which group it is, counting from 0.
+    pub group: usize,
+    /// Every item type found in all of the group's rucksacks.
+    pub common: ItemSet,
+    pub badge: Result<Item, BadgeError>,
+}
+
+impl GroupReport {
+    /// The priorities of the [common](Self::common) item types, lowest first.
+    pub fn priorities(&self) -> Vec<u8> {
+        self.common.iter().map(|item| item.priority()).collect()
+    }
+}
+
+/// A report for each group of `size` rucksacks, in order.
+///
+/// # Panics
+///
+/// If `size` is 0.
+pub fn find_badges(rucksacks: &[Rucksack], size: usize) -> Vec<GroupReport> {
+    assert!(size > 0, "groups need at least one elf");
+    rucksacks
+        .chunks(size)
+        .enumerate()
+        .map(|(group, rucksacks)| {
+            let common = rucksacks
+                .iter()
+                .map(Rucksack::item_set)
+                .reduce(|common, items| common & items)
+                .unwrap_or_default();
+            let badge = if rucksacks.len() < size {
+                Err(BadgeError::IncompleteGroup(rucksacks.len()))
+            } else {
+                let mut items = common.iter();
+                match (items.next(), items.next()) {
+                    (Some(badge), None) => Ok(badge),
+                    (None, _) => Err(BadgeError::NoBadge),
+                    (Some(_), Some(_)) => Err(BadgeError::SeveralBadges(common)),
+                }
+            };
+            GroupReport {
+                group,
+                common,
+                badge,
+            }
+        })
+        .collect()
+}
+
+/// A line per group, numbered from 1, and how many of them have a badge.
+pub fn report(reports: &[GroupReport]) -> String {
+    let mut text = String::new();
+    for report in reports {
+        let group = report.group + 1;
+        match report.badge {
+            Ok(badge) => writeln!(
+                text,
+                "group {group}: badge `{}` (priority {})",
+                badge.as_char(),
+                badge.priority()
+            ),
+            Err(err) => writeln!(
+                text,
+                "group {group}: {err} (priorities {:?})",
+                report.priorities()
+            ),
+        }
+        .unwrap();
+    }
+    let found = reports.iter().filter(|report| report.badge.is_ok()).count();
+    let priorities = reports
+        .iter()
+        .filter_map(|report| report.badge.ok())
+        .map(|badge| badge.priority() as usize)
+        .sum::<usize>();
+    writeln!(
+        text,
+        "{found} of {} groups have a badge, with priorities adding up to {priorities}",
+        reports.len()
+    )
+    .unwrap();
+    text
+}
+
+const USAGE: &str = "usage: day-03 badges <group size> [<path>]";
+
+/// The arguments after `badges`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Args {
+    pub size: usize,
+    pub source: Source,
+}
+
+impl Args {
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut args = args.into_iter();
+        let size = args::positive(&mut args, "group size")?;
+        let source = args::parse_source(args)?;
+        Ok(Self { size, source })
+    }
+}
+
+/// Run `day-03 badges` with the arguments after `badges`, exiting on any error. Exits with 1 if
+/// any group has no badge.
+pub fn main(args: impl IntoIterator<Item = String>) {
+    let args = args::or_exit(Args::parse(args), USAGE);
+    let rucksacks = input::parse_or_exit(&args.source, parse_input);
+    let reports = find_badges(&rucksacks, args.size);
+    print!("{}", report(&reports));
+    if reports.iter().any(|report| report.badge.is_err()) {
+        std::process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(item: u8) -> Item {
+        Item::try_from(item).unwrap()
+    }
+
+    #[test]
+    fn test_find_badges() {
+        let rucksacks = parse_input(include_str!("example.txt")).unwrap();
+        let reports = find_badges(&rucksacks, 3);
+        assert_eq!(reports.len(), 2);
+        assert_eq!(reports[0].badge, Ok(item(b'r')));
+        assert_eq!(reports[1].badge, Ok(item(b'Z')));
+        assert_eq!(reports[1].priorities(), [52]);
+
+        let reports = find_badges(&rucksacks, 4);
+        assert_eq!(reports[1].badge, Err(BadgeError::IncompleteGroup(2)));
+        // Every rucksack in the example has a `r` or a `Z`, but none has both.
+        let reports = find_badges(&rucksacks, 6);
+        assert_eq!(reports[0].badge, Err(BadgeError::NoBadge));
+    }
+
+    #[test]
+    fn test_several_badges() {
+        let rucksacks = parse_input("abXY\nbaYX\n").unwrap();
+        let [report] = find_badges(&rucksacks, 2)[..] else {
+            panic!("expected one group");
+        };
+        assert_eq!(
+            report.badge,
+            Err(BadgeError::SeveralBadges(ItemSet::from([
+                item(b'a'),
+                item(b'b'),
+                item(b'X'),
+                item(b'Y')
+            ])))
+        );
+        assert_eq!(report.priorities(), [1, 2, 50, 51]);
+        let text = super::report(&[report]);
+        assert!(
+            text.starts_with("group 1: the item types `abXY` are all in every"),
+            "{text}"
+        );
+    }
+}
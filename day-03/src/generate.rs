@@ -0,0 +1,88 @@
+//! Random rucksacks, in groups of three, that keep the puzzle's promises: each rucksack has
+//! exactly one item type in both compartments, and each group has exactly one badge.
+
+use aoc_core::random::Rng;
+
+const ITEMS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// Up to `size` groups of three rucksacks.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..1 + rng.below(size.max(1)) {
+        let badge = *rng.choose(ITEMS);
+        // Keep every other item type out of at least one of the three rucksacks, so that the
+        // badge is the only one they all share.
+        let missing_from = ITEMS.iter().map(|_| rng.below(3)).collect::<Vec<_>>();
+        for elf in 0..3 {
+            let allowed = ITEMS
+                .iter()
+                .zip(&missing_from)
+                .filter(|(&item, &missing)| item != badge && missing != elf)
+                .map(|(&item, _)| item)
+                .collect::<Vec<_>>();
+            input.push_str(&rucksack(rng, badge, &allowed));
+            input.push('\n');
+        }
+    }
+    input
+}
+
+/// A rucksack holding `badge` and otherwise only `allowed` items.
+fn rucksack(rng: &mut Rng, badge: u8, allowed: &[u8]) -> String {
+    let shared = if allowed.is_empty() || rng.chance(0.3) {
+        badge
+    } else {
+        *rng.choose(allowed)
+    };
+    let mut left = vec![shared];
+    let mut right = vec![shared];
+    if shared != badge {
+        if rng.chance(0.5) {
+            left.push(badge);
+        } else {
+            right.push(badge);
+        }
+    }
+
+    // Everything else goes in one compartment or the other, never both.
+    let (mut left_only, mut right_only) = (Vec::new(), Vec::new());
+    for &item in allowed.iter().filter(|&&item| item != shared) {
+        if rng.chance(0.5) {
+            left_only.push(item);
+        } else {
+            right_only.push(item);
+        }
+    }
+    let length = left.len().max(right.len()) + rng.below(16);
+    for (compartment, only) in [(&mut left, &left_only), (&mut right, &right_only)] {
+        while compartment.len() < length {
+            let item = if only.is_empty() {
+                shared
+            } else {
+                *rng.choose(only)
+            };
+            compartment.push(item);
+        }
+        rng.shuffle(compartment);
+    }
+    left.extend(right);
+    String::from_utf8(left).expect("items are ASCII letters")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day03;
+    use aoc_core::Day;
+
+    #[test]
+    fn test_solves() {
+        for seed in 0..20 {
+            let input = input(&mut Rng::new(seed), 10);
+            // Parsing checks the puzzle's promises.
+            let parsed = Day03.parse(&input).unwrap();
+            Day03.part_1(&parsed);
+            Day03.part_2(&parsed);
+        }
+    }
+}
@@ -0,0 +1,159 @@
+//! Sets of item types as bits of a `u64`, one per priority, so that sets can be compared and
+//! combined a word at a time.
+
+use crate::Item;
+use std::{
+    fmt::Debug,
+    ops::{BitAnd, BitOr},
+};
+
+/// A set of item types. Bit `p` is set when the item type with priority `p` is in the set.
+#[derive(Copy, Clone, Default, Eq, PartialEq, Hash)]
+pub struct ItemSet(u64);
+
+impl ItemSet {
+    pub const EMPTY: ItemSet = ItemSet(0);
+
+    pub fn insert(&mut self, item: Item) {
+        self.0 |= 1 << item.priority();
+    }
+
+    pub fn contains(&self, item: Item) -> bool {
+        self.0 & (1 << item.priority()) != 0
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn union(self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 | other.0)
+    }
+
+    pub fn intersection(self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 & other.0)
+    }
+
+    /// The item types in the set, from the lowest priority to the highest.
+    pub fn iter(&self) -> Items {
+        Items(self.0)
+    }
+
+    /// The sum of the priorities of the item types in the set.
+    pub fn priority_sum(&self) -> usize {
+        self.iter().map(|item| item.priority() as usize).sum()
+    }
+}
+
+impl BitOr for ItemSet {
+    type Output = ItemSet;
+
+    fn bitor(self, other: ItemSet) -> ItemSet {
+        self.union(other)
+    }
+}
+
+impl BitAnd for ItemSet {
+    type Output = ItemSet;
+
+    fn bitand(self, other: ItemSet) -> ItemSet {
+        self.intersection(other)
+    }
+}
+
+impl FromIterator<Item> for ItemSet {
+    fn from_iter<I: IntoIterator<Item = Item>>(iter: I) -> Self {
+        let mut set = ItemSet::EMPTY;
+        for item in iter {
+            set.insert(item);
+        }
+        set
+    }
+}
+
+impl<const N: usize> From<[Item; N]> for ItemSet {
+    fn from(items: [Item; N]) -> Self {
+        items.into_iter().collect()
+    }
+}
+
+impl IntoIterator for ItemSet {
+    type Item = Item;
+    type IntoIter = Items;
+
+    fn into_iter(self) -> Items {
+        self.iter()
+    }
+}
+
+/// Shows the items, like `{p, L}`.
+impl Debug for ItemSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set()
+            .entries(self.iter().map(|item| item.as_char()))
+            .finish()
+    }
+}
+
+/// The iterator returned by [`ItemSet::iter`].
+#[derive(Clone, Debug)]
+pub struct Items(u64);
+
+impl Iterator for Items {
+    type Item = Item;
+
+    fn next(&mut self) -> Option<Item> {
+        if self.0 == 0 {
+            return None;
+        }
+        let priority = self.0.trailing_zeros() as u8;
+        self.0 &= self.0 - 1;
+        Item::from_priority(priority)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.0.count_ones() as usize;
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for Items {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(items: &str) -> ItemSet {
+        items
+            .bytes()
+            .map(|item| Item::try_from(item).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = set("abcZ");
+        let b = set("cdZZ");
+        assert_eq!(a & b, set("cZ"));
+        assert_eq!(a | b, set("abcdZ"));
+        assert_eq!((a | b).len(), 5);
+        assert!(a.contains(Item::try_from(b'Z').unwrap()));
+        assert!(!a.contains(Item::try_from(b'd').unwrap()));
+        assert!((set("ab") & set("AB")).is_empty());
+    }
+
+    #[test]
+    fn test_iter() {
+        let items = set("Zazb")
+            .iter()
+            .map(|item| item.as_char())
+            .collect::<String>();
+        assert_eq!(items, "abzZ");
+        assert_eq!(set("aZ").priority_sum(), 1 + 52);
+        assert_eq!(format!("{:?}", set("pL")), "{'p', 'L'}");
+    }
+}
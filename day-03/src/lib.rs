@@ -0,0 +1,349 @@
+//! Day 3: Rucksack Reorganization.
+
+#![forbid(unsafe_code)]
+
+pub mod badges;
+pub mod generate;
+pub mod item_set;
+pub mod repack;
+
+use aoc_core::{parse_lines, random::Rng, Day, ParseError};
+use badges::find_badges;
+use item_set::ItemSet;
+use std::fmt::Display;
+
+/// Rucksack Reorganization: find the item types that were packed in the wrong place.
+pub struct Day03;
+
+impl Day for Day03 {
+    const DAY: u8 = 3;
+    type Input<'a> = Vec<Rucksack<'a>>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        parse_checked(input)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>) -> Self::Part1 {
+        part_one(input)
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>) -> Self::Part2 {
+        part_two(input)
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+}
+
+/// Parse one rucksack per line, borrowing the items from the input.
+pub fn parse_input(input: &str) -> Result<Vec<Rucksack<'_>>, ParseError> {
+    parse_lines(input.trim(), Rucksack::parse)
+}
+
+/// Like [`parse_input`], but every rucksack must also split into two compartments, as part 1
+/// needs.
+pub fn parse_packed(input: &str) -> Result<Vec<Rucksack<'_>>, ParseError> {
+    parse_lines(input.trim(), |line| {
+        let rucksack = Rucksack::parse(line)?;
+        rucksack
+            .compartments()
+            .map_err(|err| ParseError::new(err.to_string()))?;
+        Ok::<_, ParseError>(rucksack)
+    })
+}
+
+/// Like [`parse_packed`], but also holding the input to the puzzle's promises: exactly one item
+/// type in both compartments of each rucksack, and exactly one badge for each group of three.
+pub fn parse_checked(input: &str) -> Result<Vec<Rucksack<'_>>, ParseError> {
+    let rucksacks = parse_packed(input)?;
+    for rucksack in &rucksacks {
+        let common = rucksack
+            .common_items()
+            .map_err(|err| ParseError::new(err.to_string()).within(rucksack.as_str()))?;
+        if common.len() != 1 {
+            let items = common.iter().map(|item| item.as_char()).collect::<String>();
+            return Err(ParseError::new(format!(
+                "expected exactly one item type in both compartments, found {} (`{items}`)",
+                common.len()
+            ))
+            .within(rucksack.as_str()));
+        }
+    }
+    for report in find_badges(&rucksacks, 3) {
+        if let Err(err) = report.badge {
+            let first = rucksacks[report.group * 3];
+            return Err(ParseError::new(format!(
+                "the group of elves starting here has no badge: {err}"
+            ))
+            .within(first.as_str()));
+        }
+    }
+    Ok(rucksacks)
+}
+
+/// The sum of the priorities of the item types found in both compartments of each rucksack. A
+/// rucksack that can't be split into compartments adds nothing.
+pub fn part_one(input: &[Rucksack]) -> usize {
+    input
+        .iter()
+        .filter_map(|rucksack| rucksack.common_items().ok())
+        .map(|common_items| common_items.priority_sum())
+        .sum()
+}
+
+/// The sum of the priorities of the badge carried by each group of three elves. Groups without
+/// exactly one badge add nothing; [`parse_checked`] turns them away.
+pub fn part_two(input: &[Rucksack]) -> usize {
+    find_badges(input, 3)
+        .into_iter()
+        .filter_map(|report| report.badge.ok())
+        .map(|badge| badge.priority() as usize)
+        .sum()
+}
+
+/// The items in one rucksack, split evenly between two compartments.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Rucksack<'a>(&'a str);
+
+impl<'a> Rucksack<'a> {
+    /// A rucksack holding the items listed in `line`, which must all be letters.
+    pub fn parse(line: &'a str) -> Result<Self, ParseError> {
+        if let Some(index) = line.bytes().position(|item| !Item::check(item)) {
+            let len = line[index..].chars().next().map_or(0, char::len_utf8);
+            return Err(ParseError::expected(
+                Item::EXPECTED,
+                &line[index..index + len],
+            ));
+        }
+        Ok(Self(line))
+    }
+
+    /// The items, in the order they're packed.
+    pub fn items(&self) -> impl Iterator<Item = Item> + 'a {
+        self.0.bytes().map(Item)
+    }
+
+    /// Which item types are in the rucksack.
+    pub fn item_set(&self) -> ItemSet {
+        self.items().collect()
+    }
+
+    pub fn as_str(&self) -> &'a str {
+        self.0
+    }
+
+    /// Both halves of the rucksack, if it can be split evenly.
+    pub fn compartments(&self) -> Result<(Compartment<'a>, Compartment<'a>), RucksackError> {
+        if !self.0.len().is_multiple_of(2) {
+            return Err(RucksackError::OddLength(self.0.len()));
+        }
+        let (first, second) = self.0.split_at(self.0.len() / 2);
+        Ok((Compartment(first), Compartment(second)))
+    }
+
+    pub fn first_compartment(&self) -> Result<Compartment<'a>, RucksackError> {
+        self.compartments().map(|(first, _)| first)
+    }
+
+    pub fn second_compartment(&self) -> Result<Compartment<'a>, RucksackError> {
+        self.compartments().map(|(_, second)| second)
+    }
+
+    /// The item types that appear in both compartments.
+    pub fn common_items(&self) -> Result<ItemSet, RucksackError> {
+        let (first, second) = self.compartments()?;
+        Ok(first.item_set() & second.item_set())
+    }
+}
+
+/// Why a rucksack can't be split into compartments.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum RucksackError {
+    /// The rucksack has this many items, which can't be split evenly.
+    OddLength(usize),
+}
+
+impl Display for RucksackError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RucksackError::OddLength(items) => write!(
+                f,
+                "a rucksack with {items} items can't be split into two equal compartments"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for RucksackError {}
+
+/// Half of a rucksack.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Compartment<'a>(&'a str);
+
+impl<'a> Compartment<'a> {
+    pub fn items(&self) -> impl Iterator<Item = Item> + 'a {
+        self.0.bytes().map(Item)
+    }
+
+    pub fn item_set(&self) -> ItemSet {
+        self.items().collect()
+    }
+
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.0.as_bytes()
+    }
+
+    pub fn as_str(&self) -> &'a str {
+        self.0
+    }
+}
+
+/// An item type, identified by a single ASCII letter.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Item(u8);
+
+impl Item {
+    /// What an item looks like, for error messages.
+    const EXPECTED: &'static str = "an item from `a` to `z` or `A` to `Z`";
+
+    pub const fn check(input: u8) -> bool {
+        input.is_ascii_alphabetic()
+    }
+
+    /// `a` through `z` have priorities 1 through 26, and `A` through `Z` have 27 through 52.
+    pub fn priority(&self) -> u8 {
+        if self.0 >= b'a' && self.0 <= b'z' {
+            self.0 - b'a' + 1
+        } else if self.0 >= b'A' && self.0 <= b'Z' {
+            self.0 - b'A' + 27
+        } else {
+            unreachable!()
+        }
+    }
+
+    /// The item type with `priority`, if there is one.
+    pub fn from_priority(priority: u8) -> Option<Self> {
+        match priority {
+            1..=26 => Some(Self(b'a' + priority - 1)),
+            27..=52 => Some(Self(b'A' + priority - 27)),
+            _ => None,
+        }
+    }
+
+    pub fn as_char(&self) -> char {
+        char::from(self.0)
+    }
+}
+
+impl TryFrom<u8> for Item {
+    type Error = ParseError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        if Self::check(value) {
+            Ok(Self(value))
+        } else {
+            Err(ParseError::new(format!("expected {}", Self::EXPECTED)))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let str = r#"vJrwpWtwJgWrhcsFMMfFFhFp"#;
+        let rucksack = Rucksack::parse(str).expect("expect success");
+        assert_eq!(rucksack.items().next(), Some(Item::try_from(b'v').unwrap()));
+
+        let err = Rucksack::parse("vJr3p").unwrap_err();
+        assert_eq!(err.found_text(), Some("3"));
+    }
+
+    #[test]
+    fn compartments() {
+        let str = r#"vJrwpWtwJgWrhcsFMMfFFhFp"#;
+        let rucksack = Rucksack::parse(str).expect("expect success");
+        assert_eq!(
+            rucksack.first_compartment().unwrap().as_str(),
+            "vJrwpWtwJgWr"
+        );
+        assert_eq!(
+            rucksack.second_compartment().unwrap().as_str(),
+            "hcsFMMfFFhFp"
+        );
+
+        let odd = Rucksack::parse("abc").unwrap();
+        assert_eq!(odd.first_compartment(), Err(RucksackError::OddLength(3)));
+        assert_eq!(odd.common_items(), Err(RucksackError::OddLength(3)));
+        assert!(parse_packed("abcd\nabc").is_err());
+        assert!(parse_input("abcd\nabc").is_ok());
+    }
+
+    #[test]
+    fn checked() {
+        let example = include_str!("example.txt");
+        assert_eq!(parse_checked(example).unwrap().len(), 6);
+
+        let err = parse_checked("abab\nbcbc\nbdbd")
+            .unwrap_err()
+            .locate("abab\nbcbc\nbdbd");
+        assert_eq!(err.location().unwrap().line(), 1);
+        assert!(err.message().contains("found 2 (`ab`)"), "{err}");
+
+        let input = "abcb\nadbd\nefgh";
+        let err = parse_checked(input).unwrap_err().locate(input);
+        assert_eq!(err.location().unwrap().line(), 3);
+        assert!(err.message().contains("found 0"), "{err}");
+
+        let input = "abcb\nadbd\nxexy\nabcb";
+        let err = parse_checked(input).unwrap_err().locate(input);
+        assert_eq!(err.location().unwrap().line(), 1);
+        assert!(err.message().contains("no badge"), "{err}");
+
+        let input = "abcb\nadbd\nbxyb\nabcb";
+        let err = parse_checked(input).unwrap_err().locate(input);
+        assert_eq!(err.location().unwrap().line(), 4);
+        assert_eq!(
+            err.message(),
+            "the group of elves starting here has no badge: the last group only has 1 rucksack"
+        );
+
+        assert!(Day03.parse("abcb\nadbd\nbxyb").is_ok());
+    }
+
+    #[test]
+    fn priority() {
+        let item = Item::try_from(b'a').unwrap();
+        assert_eq!(item.priority(), 1);
+        let item = Item::try_from(b'z').unwrap();
+        assert_eq!(item.priority(), 26);
+        let item = Item::try_from(b'A').unwrap();
+        assert_eq!(item.priority(), 27);
+        let item = Item::try_from(b'Z').unwrap();
+        assert_eq!(item.priority(), 52);
+        for priority in 1..=52 {
+            assert_eq!(Item::from_priority(priority).unwrap().priority(), priority);
+        }
+        assert_eq!(Item::from_priority(0), None);
+    }
+
+    #[test]
+    fn common_items() {
+        let str = r#"vJrwpWtwJgWrhcsFMMfFFhFp"#;
+        let rucksack = Rucksack::parse(str).expect("expect success");
+        assert_eq!(
+            rucksack.common_items().unwrap(),
+            ItemSet::from([Item::try_from(b'p').unwrap()])
+        );
+    }
+
+    aoc_core::example_tests! {
+        test_part_1: Day03, "example.txt", part_1 => 157;
+        test_part_2: Day03, "example.txt", part_2 => 70;
+    }
+}
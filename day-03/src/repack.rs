@@ -0,0 +1,254 @@
+//! `day-03 repack`: the fewest swaps between a rucksack's compartments that leave every item type
+//! in only one of them.
+
+use crate::{parse_packed, Item, Rucksack, RucksackError};
+use aoc_core::{args, input};
+use std::fmt::{Display, Write};
+
+/// Swap the item at `first` in the first compartment with the one at `second` in the second.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Swap {
+    pub first: usize,
+    pub second: usize,
+    /// The items that were at `first` and `second` before the swap.
+    pub items: (Item, Item),
+}
+
+/// The swaps to make, and what the compartments hold after them.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Repacking {
+    pub swaps: Vec<Swap>,
+    pub first: String,
+    pub second: String,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum RepackError {
+    Rucksack(RucksackError),
+    /// No way of choosing item types for the first compartment fills it exactly.
+    Impossible,
+}
+
+impl Display for RepackError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RepackError::Rucksack(err) => err.fmt(f),
+            RepackError::Impossible => write!(
+                f,
+                "the item types can't be split between the compartments without leaving gaps"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for RepackError {}
+
+impl From<RucksackError> for RepackError {
+    fn from(err: RucksackError) -> Self {
+        RepackError::Rucksack(err)
+    }
+}
+
+/// Plan the fewest swaps that separate the item types in `rucksack`.
+///
+/// Every item type ends up wholly in one compartment. Putting a set of types in the first one
+/// works if their items exactly fill it, and then takes a swap for each of their items that
+/// starts in the second. So this is a knapsack: fill the first compartment exactly, for the
+/// fewest items brought over.
+pub fn repack(rucksack: &Rucksack) -> Result<Repacking, RepackError> {
+    let (first, second) = rucksack.compartments()?;
+    let (first, second) = (first.as_bytes(), second.as_bytes());
+    let size = first.len();
+
+    // How many of each item type, by priority, start in each compartment.
+    let mut counts = [(0, 0); 53];
+    for item in first.iter().map(|&item| Item(item)) {
+        counts[item.priority() as usize].0 += 1;
+    }
+    for item in second.iter().map(|&item| Item(item)) {
+        counts[item.priority() as usize].1 += 1;
+    }
+    let types = (1..=52u8)
+        .filter(|&priority| counts[priority as usize] != (0, 0))
+        .collect::<Vec<_>>();
+
+    // `cheapest[t][filled]`: the fewest swaps to fill `filled` places of the first compartment
+    // using only the first `t` types.
+    let mut cheapest = vec![vec![None; size + 1]; types.len() + 1];
+    cheapest[0][0] = Some(0);
+    for (t, &priority) in types.iter().enumerate() {
+        let (in_first, in_second) = counts[priority as usize];
+        for filled in 0..=size {
+            let leave = cheapest[t][filled];
+            let take = filled
+                .checked_sub(in_first + in_second)
+                .and_then(|before| cheapest[t][before])
+                .map(|swaps: usize| swaps + in_second);
+            cheapest[t + 1][filled] = match (leave, take) {
+                (Some(leave), Some(take)) => Some(leave.min(take)),
+                (leave, take) => leave.or(take),
+            };
+        }
+    }
+    if cheapest[types.len()][size].is_none() {
+        return Err(RepackError::Impossible);
+    }
+
+    // Walk back through the table to see which types went in the first compartment.
+    let mut stays_first = [false; 53];
+    let mut filled = size;
+    for (t, &priority) in types.iter().enumerate().rev() {
+        let (in_first, in_second) = counts[priority as usize];
+        if cheapest[t + 1][filled] != cheapest[t][filled] {
+            stays_first[priority as usize] = true;
+            filled -= in_first + in_second;
+        }
+    }
+
+    // Pair up the items on the wrong side of each compartment.
+    let leaving_first =
+        (0..size).filter(|&index| !stays_first[Item(first[index]).priority() as usize]);
+    let leaving_second =
+        (0..size).filter(|&index| stays_first[Item(second[index]).priority() as usize]);
+    let mut first = first.to_vec();
+    let mut second = second.to_vec();
+    let mut swaps = Vec::new();
+    for (a, b) in leaving_first.zip(leaving_second) {
+        swaps.push(Swap {
+            first: a,
+            second: b,
+            items: (Item(first[a]), Item(second[b])),
+        });
+        std::mem::swap(&mut first[a], &mut second[b]);
+    }
+    Ok(Repacking {
+        swaps,
+        first: String::from_utf8(first).expect("items are ASCII letters"),
+        second: String::from_utf8(second).expect("items are ASCII letters"),
+    })
+}
+
+/// A plan per rucksack, numbered from 1, with positions in each compartment numbered from 1.
+pub fn report(rucksacks: &[Rucksack]) -> String {
+    let mut text = String::new();
+    for (number, rucksack) in rucksacks.iter().enumerate() {
+        let number = number + 1;
+        match repack(rucksack) {
+            Ok(repacking) => {
+                let swaps = repacking
+                    .swaps
+                    .iter()
+                    .map(|swap| {
+                        format!(
+                            "{}{}<->{}{}",
+                            swap.items.0.as_char(),
+                            swap.first + 1,
+                            swap.items.1.as_char(),
+                            swap.second + 1
+                        )
+                    })
+                    .collect::<Vec<_>>();
+                writeln!(
+                    text,
+                    "rucksack {number}: {} swap{}, giving {} | {}",
+                    swaps.len(),
+                    if swaps.len() == 1 { "" } else { "s" },
+                    repacking.first,
+                    repacking.second
+                )
+                .unwrap();
+                if !swaps.is_empty() {
+                    writeln!(text, "  {}", swaps.join(" ")).unwrap();
+                }
+            }
+            Err(err) => writeln!(text, "rucksack {number}: {err}").unwrap(),
+        }
+    }
+    text
+}
+
+const USAGE: &str = "usage: day-03 repack [<path>]";
+
+/// Run `day-03 repack` with the arguments after `repack`, exiting on any error.
+pub fn main(args: impl IntoIterator<Item = String>) {
+    let source = args::or_exit(args::parse_source(args), USAGE);
+    let rucksacks = input::parse_or_exit(&source, parse_packed);
+    print!("{}", report(&rucksacks));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn repack(rucksack: &str) -> Result<Repacking, RepackError> {
+        super::repack(&Rucksack::parse(rucksack).unwrap())
+    }
+
+    /// Check that a repacking is what its swaps make, and leaves nothing in both compartments.
+    fn check(rucksack: &str, repacking: &Repacking) {
+        let (first, second) = rucksack.split_at(rucksack.len() / 2);
+        let (mut first, mut second) = (first.as_bytes().to_vec(), second.as_bytes().to_vec());
+        for swap in &repacking.swaps {
+            std::mem::swap(&mut first[swap.first], &mut second[swap.second]);
+        }
+        assert_eq!(first, repacking.first.as_bytes());
+        assert_eq!(second, repacking.second.as_bytes());
+        let packed = Rucksack::parse(rucksack).unwrap();
+        let repacked = format!("{}{}", repacking.first, repacking.second);
+        let repacked = Rucksack::parse(&repacked).unwrap();
+        assert!(repacked.common_items().unwrap().is_empty());
+        assert_eq!(packed.item_set(), repacked.item_set());
+    }
+
+    #[test]
+    fn test_repack() {
+        // `p` is in both compartments once, and the rest could go either way.
+        let rucksack = "vJrwpWtwJgWrhcsFMMfFFhFp";
+        let repacking = repack(rucksack).unwrap();
+        check(rucksack, &repacking);
+        assert_eq!(repacking.swaps.len(), 1);
+
+        let repacking = repack("abcd").unwrap();
+        assert!(repacking.swaps.is_empty());
+
+        // One swap of `a` for `b` either way round.
+        let repacking = repack("abab").unwrap();
+        check("abab", &repacking);
+        assert_eq!(repacking.swaps.len(), 1);
+    }
+
+    #[test]
+    fn test_fewest_swaps() {
+        // Swapping the one `a` for the `c` beats bringing the three `a`s over.
+        let rucksack = "bbbaaaac";
+        let repacking = repack(rucksack).unwrap();
+        check(rucksack, &repacking);
+        assert_eq!(repacking.swaps.len(), 1);
+        assert_eq!(
+            (repacking.first.as_str(), repacking.second.as_str()),
+            ("bbbc", "aaaa")
+        );
+    }
+
+    #[test]
+    fn test_impossible() {
+        // Three `a`s can't share a compartment of two.
+        assert_eq!(repack("aaab"), Err(RepackError::Impossible));
+        assert_eq!(
+            repack("abc"),
+            Err(RepackError::Rucksack(RucksackError::OddLength(3)))
+        );
+    }
+
+    #[test]
+    fn test_example() {
+        let input = include_str!("example.txt");
+        for rucksack in input.lines() {
+            if let Ok(repacking) = repack(rucksack) {
+                check(rucksack, &repacking);
+            }
+        }
+        let rucksacks = parse_packed(input).unwrap();
+        assert_eq!(report(&rucksacks).matches("rucksack ").count(), 6);
+    }
+}
@@ -0,0 +1,365 @@
+//! `day-04 coverage`: how many elves are assigned to each section, across every pair rather than
+//! within one.
+
+use crate::{parse_input, Pair};
+use aoc_core::{
+    args,
+    input::{self, Source},
+};
+use interval::{Interval, IntervalSet, IntervalTree};
+use std::fmt::{Display, Write};
+
+/// Which elf of a pair.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Side {
+    First,
+    Second,
+}
+
+/// One elf: the pair it's in, counting from 0, and which of the two it is.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Elf {
+    pub pair: usize,
+    pub side: Side,
+}
+
+/// Numbers the pair from 1, like the lines of the input.
+impl Display for Elf {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let side = match self.side {
+            Side::First => "first",
+            Side::Second => "second",
+        };
+        write!(f, "pair {} {side}", self.pair + 1)
+    }
+}
+
+/// The most elves named in the report for one stretch of sections.
+const LISTED: usize = 10;
+
+/// Every elf's assignment, ready to be asked about any section.
+pub struct Coverage {
+    tree: IntervalTree<u64, Elf>,
+    /// The overall span split wherever the number of elves changes, with that number.
+    segments: Vec<(Interval<u64>, usize)>,
+    covered: IntervalSet<u64>,
+}
+
+impl Coverage {
+    pub fn new(pairs: &[Pair]) -> Self {
+        let assignments = pairs
+            .iter()
+            .enumerate()
+            .flat_map(|(pair, sides)| {
+                [
+                    (
+                        sides.first().interval(),
+                        Elf {
+                            pair,
+                            side: Side::First,
+                        },
+                    ),
+                    (
+                        sides.second().interval(),
+                        Elf {
+                            pair,
+                            side: Side::Second,
+                        },
+                    ),
+                ]
+            })
+            .collect::<Vec<_>>();
+
+        // Each assignment adds an elf where it starts and takes one away just past its end, which
+        // is widened so that an assignment ending at the last section still gets taken away.
+        let mut changes = Vec::with_capacity(assignments.len() * 2);
+        for (interval, _) in &assignments {
+            changes.push((u128::from(interval.start()), 1));
+            changes.push((u128::from(interval.end()) + 1, -1));
+        }
+        changes.sort_unstable();
+        let mut segments: Vec<(Interval<u64>, usize)> = Vec::new();
+        let mut elves = 0isize;
+        for (index, &(section, change)) in changes.iter().enumerate() {
+            elves += change;
+            // Once every change at this section is in, the count holds until the next change. The
+            // last change always takes the last elf away, so nothing is left after it.
+            let Some(&(next, _)) = changes.get(index + 1) else {
+                break;
+            };
+            if next == section {
+                continue;
+            }
+            let sections = Interval::new(section as u64, (next - 1) as u64);
+            match segments.last_mut() {
+                // One elf's assignment ending right where another's starts changes nothing.
+                Some((last, count)) if *count == elves as usize => {
+                    *last = Interval::new(last.start(), sections.end())
+                }
+                _ => segments.push((sections, elves as usize)),
+            }
+        }
+
+        Self {
+            covered: assignments.iter().map(|&(interval, _)| interval).collect(),
+            tree: assignments.into_iter().collect(),
+            segments,
+        }
+    }
+
+    /// From the lowest section anyone is assigned to the highest, if anyone is assigned anything.
+    pub fn span(&self) -> Option<Interval<u64>> {
+        self.covered.span()
+    }
+
+    /// How many elves are assigned to `section`.
+    pub fn count(&self, section: u64) -> usize {
+        self.tree.containing(section).len()
+    }
+
+    /// The elves assigned to `section`, in the order their assignments start.
+    pub fn who_covers(&self, section: u64) -> Vec<Elf> {
+        self.tree
+            .containing(section)
+            .into_iter()
+            .map(|&(_, elf)| elf)
+            .collect()
+    }
+
+    /// How many elves are assigned to each section of the [span](Self::span), with neighbouring
+    /// sections that have the same number of elves grouped together.
+    pub fn counts(&self) -> &[(Interval<u64>, usize)] {
+        &self.segments
+    }
+
+    /// The sections with more than one elf assigned, grouped like [`Self::counts`], with the most
+    /// crowded first. Sections with as many elves as each other stay in order.
+    pub fn most_assigned(&self) -> Vec<(Interval<u64>, usize)> {
+        let mut crowded = self
+            .segments
+            .iter()
+            .copied()
+            .filter(|&(_, elves)| elves > 1)
+            .collect::<Vec<_>>();
+        crowded.sort_by_key(|&(_, elves)| std::cmp::Reverse(elves));
+        crowded
+    }
+
+    /// The sections within the span that nobody is assigned to.
+    pub fn uncovered(&self) -> Vec<Interval<u64>> {
+        self.covered.gaps().collect()
+    }
+
+    /// Everything above, for people.
+    pub fn report(&self) -> String {
+        let mut text = String::new();
+        let Some(span) = self.span() else {
+            return String::from("nobody is assigned any sections\n");
+        };
+        writeln!(
+            text,
+            "sections {} to {}, assigned to {} elves",
+            span.start(),
+            span.end(),
+            self.tree.len()
+        )
+        .unwrap();
+
+        writeln!(text, "\nelves per section:").unwrap();
+        for (sections, elves) in self.counts() {
+            writeln!(text, "  {:<10} {elves}", sections_text(sections)).unwrap();
+        }
+
+        writeln!(text, "\nmost assigned:").unwrap();
+        let most_assigned = self.most_assigned();
+        if most_assigned.is_empty() {
+            writeln!(text, "  no section has more than one elf").unwrap();
+        }
+        for &(sections, _) in most_assigned.iter().take(LISTED) {
+            let mut elves = self.who_covers(sections.start());
+            elves.sort();
+            let mut listed = elves
+                .iter()
+                .take(LISTED)
+                .map(Elf::to_string)
+                .collect::<Vec<_>>();
+            if elves.len() > LISTED {
+                listed.push(format!("and {} more", elves.len() - LISTED));
+            }
+            writeln!(
+                text,
+                "  {:<10} {} elves: {}",
+                sections_text(&sections),
+                elves.len(),
+                listed.join(", ")
+            )
+            .unwrap();
+        }
+        if most_assigned.len() > LISTED {
+            writeln!(
+                text,
+                "  and {} more stretches of sections",
+                most_assigned.len() - LISTED
+            )
+            .unwrap();
+        }
+
+        let uncovered = self.uncovered();
+        let uncovered = if uncovered.is_empty() {
+            String::from("none")
+        } else {
+            uncovered
+                .iter()
+                .map(sections_text)
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        writeln!(text, "\nuncovered: {uncovered}").unwrap();
+        text
+    }
+}
+
+/// `4` for a single section, `2-4` for a few, like the input.
+fn sections_text(sections: &Interval<u64>) -> String {
+    if sections.start() == sections.end() {
+        sections.start().to_string()
+    } else {
+        format!("{}-{}", sections.start(), sections.end())
+    }
+}
+
+const USAGE: &str = "usage: day-04 coverage [--who <section>] [<path>]";
+
+/// The arguments after `coverage`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Args {
+    pub source: Source,
+    /// Only say who covers this section.
+    pub who: Option<u64>,
+}
+
+impl Args {
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut who = None;
+        let source = args::parse_options(args, |name, value| match name {
+            "--who" => {
+                let section = value
+                    .parse()
+                    .map_err(|_| format!("`{value}` is not a section"))?;
+                who = Some(section);
+                Ok(true)
+            }
+            _ => Ok(false),
+        })?;
+        Ok(Self { source, who })
+    }
+}
+
+/// Run `day-04 coverage` with the arguments after `coverage`, exiting on any error.
+pub fn main(args: impl IntoIterator<Item = String>) {
+    let args = args::or_exit(Args::parse(args), USAGE);
+    let pairs = input::parse_or_exit(&args.source, parse_input);
+    let coverage = Coverage::new(&pairs);
+    match args.who {
+        Some(section) => {
+            let elves = coverage.who_covers(section);
+            println!("section {section}: {} elves", elves.len());
+            for elf in elves {
+                println!("  {elf}");
+            }
+        }
+        None => print!("{}", coverage.report()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn coverage(input: &str) -> Coverage {
+        Coverage::new(&parse_input(input).unwrap())
+    }
+
+    #[test]
+    fn test_counts() {
+        let coverage = coverage(include_str!("example.txt"));
+        assert_eq!(coverage.span(), Some(Interval::new(2, 9)));
+        let counts = (2..=9)
+            .map(|section| coverage.count(section))
+            .collect::<Vec<_>>();
+        assert_eq!(counts, [4, 5, 7, 7, 8, 6, 4, 1]);
+        let segments = coverage
+            .counts()
+            .iter()
+            .flat_map(|&(sections, elves)| sections.iter().map(move |_| elves))
+            .collect::<Vec<_>>();
+        assert_eq!(segments, counts);
+        assert_eq!(coverage.count(1), 0);
+    }
+
+    #[test]
+    fn test_most_assigned() {
+        let coverage = coverage(include_str!("example.txt"));
+        assert_eq!(
+            coverage.most_assigned(),
+            [
+                (Interval::single(6), 8),
+                (Interval::new(4, 5), 7),
+                (Interval::single(7), 6),
+                (Interval::single(3), 5),
+                (Interval::single(2), 4),
+                (Interval::single(8), 4)
+            ]
+        );
+        let elves = coverage.who_covers(6);
+        assert_eq!(elves.len(), 8);
+        assert!(elves.contains(&Elf {
+            pair: 4,
+            side: Side::First
+        }));
+        assert!(coverage.uncovered().is_empty());
+    }
+
+    #[test]
+    fn test_uncovered() {
+        let coverage = coverage("1-3,10-12\n2-4,20-20\n");
+        assert_eq!(
+            coverage.uncovered(),
+            [Interval::new(5, 9), Interval::new(13, 19)]
+        );
+        assert_eq!(coverage.most_assigned(), [(Interval::new(2, 3), 2)]);
+        assert_eq!(
+            coverage.counts()[..3],
+            [
+                (Interval::new(1, 1), 1),
+                (Interval::new(2, 3), 2),
+                (Interval::single(4), 1)
+            ]
+        );
+        let report = coverage.report();
+        assert!(report.contains("\nuncovered: 5-9, 13-19\n"), "{report}");
+        assert!(
+            report.contains("2 elves: pair 1 first, pair 2 first"),
+            "{report}"
+        );
+    }
+
+    #[test]
+    fn test_last_section() {
+        let max = u64::MAX;
+        let coverage = coverage(&format!("1-{max},{max}-{max}\n"));
+        assert_eq!(
+            coverage.counts(),
+            [(Interval::new(1, max - 1), 1), (Interval::single(max), 2)]
+        );
+        assert_eq!(coverage.count(max), 2);
+        assert_eq!(coverage.most_assigned(), [(Interval::single(max), 2)]);
+    }
+
+    #[test]
+    fn test_args() {
+        let args = Args::parse(["--who".to_string(), "57".to_string()]).unwrap();
+        assert_eq!(args.who, Some(57));
+        assert!(Args::parse(["--who".to_string(), "x".to_string()]).is_err());
+    }
+}
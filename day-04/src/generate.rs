@@ -0,0 +1,33 @@
+//! Random section assignments for pairs of elves.
+
+use aoc_core::random::Rng;
+
+/// `size` pairs of section ranges.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size.max(1) {
+        let (a, b) = (range(rng), range(rng));
+        input.push_str(&format!("{}-{},{}-{}\n", a.0, a.1, b.0, b.1));
+    }
+    input
+}
+
+fn range(rng: &mut Rng) -> (i64, i64) {
+    let start = rng.range(1..=99);
+    (start, rng.range(start..=99))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day04;
+    use aoc_core::Day;
+
+    #[test]
+    fn test_parses() {
+        for seed in 0..20 {
+            let input = input(&mut Rng::new(seed), 10);
+            assert!(Day04.parse(&input).is_ok(), "seed {seed}:\n{input}");
+        }
+    }
+}
@@ -0,0 +1,168 @@
+//! Day 4: Camp Cleanup.
+
+pub mod coverage;
+pub mod generate;
+
+use aoc_core::{parse_lines, random::Rng, Day, ParseError};
+use interval::Interval;
+use std::str::FromStr;
+
+/// Camp Cleanup: pairs of elves with overlapping section assignments.
+pub struct Day04;
+
+impl Day for Day04 {
+    const DAY: u8 = 4;
+    type Input<'a> = Vec<Pair>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>) -> Self::Part1 {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>) -> Self::Part2 {
+        part_2(input)
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+}
+
+/// Parse one pair of assignments per line.
+pub fn parse_input(input: &str) -> Result<Vec<Pair>, ParseError> {
+    parse_lines(input.trim(), str::parse)
+}
+
+/// The number of pairs where one assignment fully contains the other.
+pub fn part_1(input: &[Pair]) -> usize {
+    let mut count = 0;
+    for pair in input {
+        if pair.first.contains(&pair.second) || pair.second.contains(&pair.first) {
+            count += 1;
+        }
+    }
+    count
+}
+
+/// The number of pairs whose assignments overlap at all.
+pub fn part_2(input: &[Pair]) -> usize {
+    let mut count = 0;
+    for pair in input {
+        if pair.first.overlaps(&pair.second) {
+            count += 1;
+        }
+    }
+    count
+}
+
+/// The sections assigned to one elf, inclusive on both ends, written `2-4`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Range(Interval<u64>);
+
+impl Range {
+    /// # Panics
+    ///
+    /// If `min` is after `max`.
+    pub fn new(min: u64, max: u64) -> Self {
+        Self(Interval::new(min, max))
+    }
+
+    pub fn min(&self) -> u64 {
+        self.0.start()
+    }
+
+    pub fn max(&self) -> u64 {
+        self.0.end()
+    }
+
+    pub fn interval(&self) -> Interval<u64> {
+        self.0
+    }
+
+    /// Whether every section of `other` is also in `self`.
+    pub fn contains(&self, other: &Range) -> bool {
+        self.0.contains_interval(&other.0)
+    }
+
+    /// Whether `self` and `other` share at least one section.
+    pub fn overlaps(&self, other: &Range) -> bool {
+        self.0.overlaps(&other.0)
+    }
+}
+
+impl FromStr for Range {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (min, max) = s
+            .split_once('-')
+            .ok_or_else(|| ParseError::expected("a range like `2-4`", s))?;
+        let min = min
+            .parse()
+            .map_err(|_| ParseError::expected("a section number", min))?;
+        let max = max
+            .parse()
+            .map_err(|_| ParseError::expected("a section number", max))?;
+        Interval::checked(min, max)
+            .map(Range)
+            .ok_or_else(|| ParseError::expected("a range that doesn't end before it starts", s))
+    }
+}
+
+/// The assignments for a pair of elves, written `2-4,6-8`.
+pub struct Pair {
+    first: Range,
+    second: Range,
+}
+
+impl Pair {
+    pub fn new(first: Range, second: Range) -> Self {
+        Self { first, second }
+    }
+
+    pub fn first(&self) -> &Range {
+        &self.first
+    }
+
+    pub fn second(&self) -> &Range {
+        &self.second
+    }
+}
+
+impl FromStr for Pair {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (first, second) = s
+            .split_once(',')
+            .ok_or_else(|| ParseError::expected("two ranges separated by a comma", s))?;
+        let first = first.parse()?;
+        let second = second.parse()?;
+        Ok(Pair { first, second })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_range() {
+        let range = "2-8".parse::<Range>().unwrap();
+        assert_eq!((range.min(), range.max()), (2, 8));
+        assert!(range.contains(&Range::new(3, 7)));
+        assert!(range.overlaps(&Range::new(8, 9)));
+        assert!(!range.overlaps(&Range::new(9, 9)));
+        assert!("8-2".parse::<Range>().is_err());
+    }
+
+    aoc_core::example_tests! {
+        test_part_1: Day04, "example.txt", part_1 => 2;
+        test_part_2: Day04, "example.txt", part_2 => 4;
+    }
+}
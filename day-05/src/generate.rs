@@ -0,0 +1,88 @@
+//! Random stacks of crates and a procedure to rearrange them. The procedure never moves more
+//! crates than a stack holds, but it can leave stacks empty.
+
+use aoc_core::random::Rng;
+
+/// Up to nine stacks of up to `size` crates each, and `size` steps of the procedure.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let mut stacks = (0..1 + rng.below(9))
+        .map(|_| {
+            (0..rng.below(size + 1))
+                .map(|_| (b'A' + rng.below(26) as u8) as char)
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let mut input = drawing(&stacks);
+    input.push('\n');
+
+    for _ in 0..size {
+        let sources = (0..stacks.len())
+            .filter(|&stack| !stacks[stack].is_empty())
+            .collect::<Vec<_>>();
+        if stacks.len() < 2 || sources.is_empty() {
+            break;
+        }
+        let source = *rng.choose(&sources);
+        let mut destination = rng.below(stacks.len() - 1);
+        if destination >= source {
+            destination += 1;
+        }
+        let count = 1 + rng.below(stacks[source].len());
+        let at = stacks[source].len() - count;
+        let moved = stacks[source].split_off(at);
+        stacks[destination].extend(moved);
+        input.push_str(&format!(
+            "move {count} from {} to {}\n",
+            source + 1,
+            destination + 1
+        ));
+    }
+    input
+}
+
+/// The drawing of the stacks, bottom crate first in each, padded out to the full width like the
+/// real one.
+fn drawing(stacks: &[Vec<char>]) -> String {
+    let height = stacks.iter().map(Vec::len).max().unwrap_or(0);
+    let mut drawing = String::new();
+    for layer in (0..height).rev() {
+        let row = stacks
+            .iter()
+            .map(|stack| match stack.get(layer) {
+                Some(c) => format!("[{c}]"),
+                None => String::from("   "),
+            })
+            .collect::<Vec<_>>();
+        drawing.push_str(&row.join(" "));
+        drawing.push('\n');
+    }
+    let labels = (1..=stacks.len())
+        .map(|label| format!(" {label} "))
+        .collect::<Vec<_>>();
+    drawing.push_str(&labels.join(" "));
+    drawing.push('\n');
+    drawing
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_input;
+
+    #[test]
+    fn test_drawing() {
+        let stacks = [vec!['Z', 'N'], vec!['M', 'C', 'D'], vec!['P']];
+        assert_eq!(
+            drawing(&stacks),
+            "    [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 \n"
+        );
+    }
+
+    #[test]
+    fn test_parses() {
+        for seed in 0..20 {
+            let input = input(&mut Rng::new(seed), 10);
+            assert!(parse_input(&input).is_ok(), "seed {seed}:\n{input}");
+        }
+    }
+}
@@ -0,0 +1,268 @@
+//! Day 5: Supply Stacks.
+
+pub mod generate;
+
+use aoc_core::{parse_lines, random::Rng, Day, ParseError};
+use std::{collections::VecDeque, str::FromStr};
+
+/// Supply Stacks: follow the crane operator's procedure and read off the top crates.
+pub struct Day05;
+
+impl Day for Day05 {
+    const DAY: u8 = 5;
+    type Input<'a> = (Dock, Vec<Command>);
+    type Part1 = String;
+    type Part2 = String;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_1(&self, (dock, commands): &Self::Input<'_>) -> Self::Part1 {
+        part_1(dock.clone(), commands)
+    }
+
+    fn part_2(&self, (dock, commands): &Self::Input<'_>) -> Self::Part2 {
+        part_2(dock.clone(), commands)
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+}
+
+/// Parse the drawing of the starting stacks and the rearrangement procedure below it.
+pub fn parse_input(input: &str) -> Result<(Dock, Vec<Command>), ParseError> {
+    let (drawing, commands) = input.split_once("\n\n").ok_or_else(|| {
+        ParseError::expected("a blank line between the stacks and the commands", input)
+    })?;
+    let dock = drawing.parse()?;
+    let commands = parse_lines(commands.trim(), str::parse)?;
+    Ok((dock, commands))
+}
+
+/// The top crates after a CrateMover 9000 runs the procedure, moving one crate at a time.
+pub fn part_1(mut dock: Dock, commands: &[Command]) -> String {
+    for command in commands {
+        dock.handle_command(command);
+    }
+    dock.tops()
+}
+
+/// The top crates after a CrateMover 9001 runs the procedure, moving several crates at once.
+pub fn part_2(mut dock: Dock, commands: &[Command]) -> String {
+    for command in commands {
+        dock.handle_command_v2(command);
+    }
+    dock.tops()
+}
+
+/// Every stack of crates, indexed from zero.
+#[derive(Clone)]
+pub struct Dock {
+    stacks: Vec<Stack>,
+}
+
+impl Dock {
+    pub fn move_one(&mut self, source: usize, destination: usize) {
+        let source_stack = &mut self.stacks[source];
+        let c = source_stack.pop();
+        let destination_stack = &mut self.stacks[destination];
+        destination_stack.push(c);
+    }
+
+    /// Run a command the way the CrateMover 9000 does: one crate at a time.
+    pub fn handle_command(&mut self, command: &Command) {
+        let source = command.source;
+        let destination = command.destination;
+        for _ in 0..command.count {
+            self.move_one(source, destination);
+        }
+    }
+
+    pub fn move_multiple_v2(&mut self, source: usize, destination: usize, count: usize) {
+        let source_stack = &mut self.stacks[source];
+        let on_crane = source_stack.pop_stack(count);
+        let dest_stack = &mut self.stacks[destination];
+        dest_stack.push_stack(on_crane);
+    }
+
+    /// Run a command the way the CrateMover 9001 does: all of the crates at once, keeping their
+    /// order.
+    pub fn handle_command_v2(&mut self, command: &Command) {
+        let source = command.source;
+        let destination = command.destination;
+        self.move_multiple_v2(source, destination, command.count);
+    }
+
+    /// The crate on top of each stack, in stack order.
+    pub fn tops(&self) -> String {
+        let mut string = String::new();
+        for stack in &self.stacks {
+            string.push(stack.peek());
+        }
+        string
+    }
+
+    pub fn from_stacks<const C: usize>(stacks: [Stack; C]) -> Dock {
+        let stacks: Vec<Stack> = Vec::from(stacks);
+        Self { stacks }
+    }
+}
+
+impl FromStr for Dock {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // The drawing is read bottom-up: first the row of stack labels, then each layer of crates
+        // from the floor to the top.
+        let mut lines = s.lines().rev();
+        let labels = lines
+            .next()
+            .ok_or_else(|| ParseError::expected("a drawing of the stacks", s))?;
+        let count = labels.split_whitespace().count();
+        let mut stacks = vec![Stack(VecDeque::new()); count];
+        for line in lines {
+            let bytes = line.as_bytes();
+            for (idx, stack) in stacks.iter_mut().enumerate() {
+                match bytes.get(idx * 4 + 1) {
+                    None | Some(b' ') => {}
+                    Some(c) if c.is_ascii_alphabetic() => stack.push(*c as char),
+                    Some(_) => {
+                        let found = line.get(idx * 4 + 1..idx * 4 + 2).unwrap_or(line);
+                        return Err(ParseError::expected(
+                            "a crate labelled with a letter",
+                            found,
+                        ));
+                    }
+                }
+            }
+        }
+        Ok(Self { stacks })
+    }
+}
+
+/// A single stack of crates. The front of the queue is the top of the stack.
+#[derive(Debug, Clone)]
+pub struct Stack(VecDeque<char>);
+
+impl Stack {
+    pub fn push(&mut self, c: char) {
+        self.0.push_front(c);
+    }
+
+    pub fn pop(&mut self) -> char {
+        self.0.pop_front().unwrap()
+    }
+
+    pub fn pop_stack(&mut self, size: usize) -> Stack {
+        let mut queue = VecDeque::with_capacity(size);
+        for _ in 0..size {
+            queue.push_back(self.pop());
+        }
+        Stack(queue)
+    }
+
+    pub fn push_stack(&mut self, mut stack: Stack) {
+        while let Some(c) = stack.0.pop_back() {
+            self.push(c)
+        }
+    }
+
+    pub fn peek(&self) -> char {
+        *self.0.front().unwrap()
+    }
+}
+
+/// One step of the procedure, written `move 1 from 2 to 1`. Stacks are numbered from one in the
+/// input, but from zero here.
+#[derive(Debug)]
+pub struct Command {
+    count: usize,
+    source: usize,
+    destination: usize,
+}
+
+impl Command {
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    pub fn source(&self) -> usize {
+        self.source
+    }
+
+    pub fn destination(&self) -> usize {
+        self.destination
+    }
+}
+
+impl FromStr for Command {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let tmp = s
+            .strip_prefix("move ")
+            .ok_or_else(|| ParseError::expected("`move`", s))?;
+        let (num, rest) = tmp
+            .split_once(' ')
+            .ok_or_else(|| ParseError::expected("a count followed by a space", tmp))?;
+        let count = num
+            .parse::<usize>()
+            .map_err(|_| ParseError::expected("a count", num))?;
+        let tmp = rest
+            .strip_prefix("from ")
+            .ok_or_else(|| ParseError::expected("`from`", rest))?;
+        let (src, rest) = tmp
+            .split_once(' ')
+            .ok_or_else(|| ParseError::expected("a stack number followed by a space", tmp))?;
+        let source = stack_index(src)?;
+        let tmp = rest
+            .strip_prefix("to ")
+            .ok_or_else(|| ParseError::expected("`to`", rest))?;
+        let destination = stack_index(tmp)?;
+        Ok(Command {
+            count,
+            source,
+            destination,
+        })
+    }
+}
+
+/// Turn a stack number from the input, which counts from one, into an index.
+fn stack_index(s: &str) -> Result<usize, ParseError> {
+    match s.parse::<usize>() {
+        Ok(number) if number > 0 => Ok(number - 1),
+        _ => Err(ParseError::expected("a stack number", s)),
+    }
+}
+
+/// Parse every line that is a command, skipping the rest (like the drawing of the stacks).
+pub fn parse_commands(input: &str) -> Vec<Command> {
+    input
+        .trim()
+        .lines()
+        .filter_map(|line| line.parse::<Command>().ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_input() {
+        let input = include_str!("example.txt");
+        let (dock, commands) = parse_input(input).unwrap();
+        assert_eq!(dock.tops(), "NDP");
+        assert_eq!(commands.len(), 4);
+
+        let result = part_1(dock, &commands);
+        assert_eq!(result, "CMZ");
+    }
+
+    aoc_core::example_tests! {
+        test_part_1: Day05, "example.txt", part_1 => "CMZ";
+        test_part_2: Day05, "example.txt", part_2 => "MCD";
+    }
+}
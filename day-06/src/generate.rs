@@ -0,0 +1,30 @@
+//! Random datastreams. With only a few letters to draw from, a stream may never have a marker.
+
+use aoc_core::random::Rng;
+
+/// A stream of `size` to `10 * size` lowercase letters, drawn from the first few of the alphabet.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    let letters = 2 + rng.below(25) as u8;
+    let mut input = (0..size + rng.below(9 * size + 1))
+        .map(|_| (b'a' + rng.below(letters as usize) as u8) as char)
+        .collect::<String>();
+    input.push('\n');
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day06;
+    use aoc_core::Day;
+
+    #[test]
+    fn test_parses() {
+        for seed in 0..20 {
+            let input = input(&mut Rng::new(seed), 10);
+            let parsed = Day06.parse(&input).unwrap();
+            assert!(parsed.bytes().all(|c| c.is_ascii_lowercase()));
+        }
+    }
+}
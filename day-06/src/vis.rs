@@ -0,0 +1,111 @@
+//! An animation of a window sliding along the datastream, growing while its characters are all
+//! different and shrinking from the front when one repeats.
+
+use vis::{Canvas, Visualize};
+
+pub struct Visualization {
+    bytes: Vec<u8>,
+    start: usize,
+    good_start: usize,
+    end: usize,
+    valid: bool,
+}
+
+impl Visualization {
+    pub fn new(datastream: &str) -> Self {
+        Visualization {
+            bytes: datastream.as_bytes().to_vec(),
+            start: 0,
+            good_start: 0,
+            end: 0,
+            valid: true,
+        }
+    }
+
+    /// Where the window would start if it only held distinct characters: just after the last
+    /// earlier copy of its newest character.
+    fn find_good_start(&self) -> usize {
+        let mut good_start = self.start;
+        let last_byte = self.bytes[self.end - 1];
+        for idx in self.start..(self.end - 1) {
+            if self.bytes[idx] == last_byte {
+                good_start = idx + 1;
+            }
+        }
+        good_start
+    }
+
+    fn tick_one(&mut self) -> bool {
+        if self.end >= self.bytes.len() {
+            return true;
+        }
+        if self.valid {
+            self.end += 1;
+        } else {
+            self.start += 1;
+        }
+        self.good_start = self.find_good_start();
+        self.valid = self.start == self.good_start;
+
+        self.end >= self.bytes.len()
+    }
+}
+
+impl Visualize for Visualization {
+    /// Every step shows the window with a repeated character in it; otherwise the window skips
+    /// straight to its next valid state.
+    fn tick(&mut self, every_step: bool) -> bool {
+        let mut end_of_buffer = self.tick_one();
+        while !every_step && !end_of_buffer && !self.valid {
+            end_of_buffer = self.tick_one();
+        }
+        end_of_buffer
+    }
+
+    fn draw(&self, canvas: &Canvas) {
+        canvas.clear();
+        canvas.gray();
+
+        for (idx, byte) in self.bytes.iter().enumerate() {
+            let x = idx % Canvas::SIZE;
+            let y = idx / Canvas::SIZE;
+
+            if self.start <= idx && idx < self.end {
+                if idx < self.good_start {
+                    canvas.dark_red()
+                } else {
+                    canvas.dark_green()
+                }
+                canvas.fill_box(x, y);
+
+                if idx < self.good_start {
+                    canvas.bright_red()
+                } else {
+                    canvas.bright_green()
+                }
+            } else {
+                canvas.gray();
+            }
+
+            canvas.draw_byte(x, y, *byte)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tick() {
+        let mut vis = Visualization::new("abca");
+        assert!(!vis.tick(false));
+        assert!(!vis.tick(false));
+        assert!(!vis.tick(false));
+        assert_eq!((vis.start, vis.end), (0, 3));
+        assert!(vis.tick(true));
+        assert_eq!((vis.start, vis.good_start, vis.end), (0, 1, 4));
+        assert!(!vis.valid);
+        assert!(Visualization::new("").tick(true));
+    }
+}
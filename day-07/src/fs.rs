@@ -1,5 +1,9 @@
+//! An in-memory file system tree.
+
+use aoc_core::ParseError;
 use std::{collections::VecDeque, fmt::Display, str::FromStr};
 
+/// Anything that can be found in a directory.
 pub enum Entry {
     Directory(Directory),
     File(File),
@@ -43,6 +47,7 @@ impl Entry {
         }
     }
 
+    /// Follow a path down from this entry.
     pub fn pointer(&self, pointer: Pointer) -> Option<&Entry> {
         if pointer.is_empty() {
             Some(self)
@@ -63,6 +68,7 @@ impl Entry {
         }
     }
 
+    /// Walk every entry below this one, depth first.
     pub fn visit(&self, visitor: &mut impl Visitor) {
         let pointer = Pointer::new();
         self.visit_internal(pointer, visitor);
@@ -100,6 +106,7 @@ impl From<File> for Entry {
     }
 }
 
+/// A named directory and the entries inside it.
 pub struct Directory {
     name: String,
     children: Vec<Entry>,
@@ -151,6 +158,7 @@ impl Directory {
     }
 }
 
+/// A named file with a size.
 pub struct File {
     name: String,
     size: usize,
@@ -169,6 +177,7 @@ impl File {
     }
 }
 
+/// A path from the root of the file system, like `/a/e`.
 #[derive(Clone, Debug)]
 pub struct Pointer(VecDeque<String>);
 
@@ -195,10 +204,12 @@ impl Pointer {
 }
 
 impl FromStr for Pointer {
-    type Err = &'static str;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let rest = s.strip_prefix('/').ok_or("Must begin with a '/'")?;
+        let rest = s
+            .strip_prefix('/')
+            .ok_or_else(|| ParseError::expected("a path starting with `/`", s))?;
         let queue = rest.split('/').map(|v| v.to_string()).collect();
         Ok(Self(queue))
     }
@@ -218,6 +229,13 @@ impl Display for Pointer {
     }
 }
 
+/// Callbacks for [`Entry::visit`]. Each has a default that does nothing.
+pub trait Visitor {
+    fn visit_directory_before(&mut self, _pointer: &Pointer, _directory: &Directory) {}
+    fn visit_directory_after(&mut self, _pointer: &Pointer, _directory: &Directory) {}
+    fn visit_file(&mut self, _pointer: &Pointer, _file: &File) {}
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -290,9 +308,3 @@ mod tests {
         assert_eq!(entry.as_file().unwrap().size(), 584);
     }
 }
-
-pub trait Visitor {
-    fn visit_directory_before(&mut self, pointer: &Pointer, directory: &Directory) {}
-    fn visit_directory_after(&mut self, pointer: &Pointer, directory: &Directory) {}
-    fn visit_file(&mut self, pointer: &Pointer, file: &File) {}
-}
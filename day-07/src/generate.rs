@@ -0,0 +1,112 @@
+//! Random terminal sessions that explore a whole file system, depth first, and never fill the
+//! disk.
+
+use aoc_core::random::Rng;
+use std::collections::HashSet;
+
+struct Directory {
+    name: String,
+    /// The relative size of each file, scaled to the disk usage when the session is written out.
+    files: Vec<(u64, String)>,
+    directories: Vec<Directory>,
+}
+
+/// A session listing about `size` files.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let mut files = size.max(1);
+    let root = directory(rng, String::from("/"), &mut files, 0);
+    let weights = total_weight(&root);
+    // Anywhere from plenty of space for the update to none at all, but always within the disk.
+    let used = rng.range(10_000_000..=69_990_000) as u64;
+
+    let mut lines = Vec::new();
+    session(&root, used, weights, &mut lines);
+    // Like the real session, don't bother climbing back out at the end.
+    while lines.last().is_some_and(|line| line == "$ cd ..") {
+        lines.pop();
+    }
+    lines.join("\n") + "\n"
+}
+
+fn directory(rng: &mut Rng, name: String, files: &mut usize, depth: usize) -> Directory {
+    let mut names = HashSet::new();
+    let mut directory = Directory {
+        name,
+        files: Vec::new(),
+        directories: Vec::new(),
+    };
+    for _ in 0..rng.below(4).min(*files) {
+        *files -= 1;
+        let name = unique_name(rng, &mut names, true);
+        directory.files.push((1 + rng.below(1000) as u64, name));
+    }
+    if depth < 5 {
+        for _ in 0..rng.below(4) {
+            if *files == 0 {
+                break;
+            }
+            let name = unique_name(rng, &mut names, false);
+            let child = self::directory(rng, name, files, depth + 1);
+            directory.directories.push(child);
+        }
+    }
+    directory
+}
+
+fn unique_name(rng: &mut Rng, names: &mut HashSet<String>, extension: bool) -> String {
+    loop {
+        let mut name = word(rng);
+        if extension && rng.chance(0.5) {
+            name = format!("{name}.{}", word(rng));
+        }
+        if names.insert(name.clone()) {
+            return name;
+        }
+    }
+}
+
+fn word(rng: &mut Rng) -> String {
+    (0..1 + rng.below(8))
+        .map(|_| (b'a' + rng.below(26) as u8) as char)
+        .collect()
+}
+
+fn total_weight(directory: &Directory) -> u64 {
+    let files = directory
+        .files
+        .iter()
+        .map(|(weight, _)| weight)
+        .sum::<u64>();
+    files + directory.directories.iter().map(total_weight).sum::<u64>()
+}
+
+/// `cd` into the directory, list it, and then do the same for each directory inside it.
+fn session(directory: &Directory, used: u64, weights: u64, lines: &mut Vec<String>) {
+    lines.push(format!("$ cd {}", directory.name));
+    lines.push(String::from("$ ls"));
+    for child in &directory.directories {
+        lines.push(format!("dir {}", child.name));
+    }
+    for (weight, name) in &directory.files {
+        let size = (used * weight / weights.max(1)).max(1);
+        lines.push(format!("{size} {name}"));
+    }
+    for child in &directory.directories {
+        session(child, used, weights, lines);
+        lines.push(String::from("$ cd .."));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::parse_input;
+
+    #[test]
+    fn test_parses() {
+        for seed in 0..20 {
+            let input = input(&mut Rng::new(seed), 10);
+            assert!(parse_input(&input).is_ok(), "seed {seed}:\n{input}");
+        }
+    }
+}
@@ -1,5 +1,9 @@
+//! Parsing the terminal session.
+
 use crate::fs;
+use aoc_core::{parse_lines, ParseError};
 
+/// One line of the terminal session: either a command or a line of `ls` output.
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum Input<'a> {
     CdRoot,
@@ -11,7 +15,7 @@ pub enum Input<'a> {
 }
 
 impl<'a> Input<'a> {
-    pub fn from_str(line: &'a str) -> Result<Self, &'static str> {
+    pub fn parse(line: &'a str) -> Result<Self, ParseError> {
         if line == "$ cd /" {
             Ok(Input::CdRoot)
         } else if line == "$ cd .." {
@@ -23,30 +27,45 @@ impl<'a> Input<'a> {
         } else if let Some(name) = line.strip_prefix("dir ") {
             Ok(Input::DirectoryLine { name })
         } else if let Some((size, name)) = line.split_once(' ') {
-            let size = size.parse::<usize>().map_err(|_| "invalid size for file")?;
+            let size = size
+                .parse::<usize>()
+                .map_err(|_| ParseError::expected("a file size", size))?;
             Ok(Input::FileLine { size, name })
         } else {
-            Err("Invalid format")
+            Err(ParseError::expected(
+                "a command or a line of `ls` output",
+                line,
+            ))
         }
     }
 }
 
-pub fn build_fs(input: Vec<Input<'_>>) -> Result<fs::Entry, &'static str> {
-    let mut iter = input.into_iter();
-    let first = iter.next().ok_or("Expected the first line of input")?;
+/// Replay the terminal session to rebuild the file system it explored. Each input comes with the
+/// line it was parsed from, so that errors can point at it.
+pub fn build_fs<'a>(
+    session: impl IntoIterator<Item = (&'a str, Input<'a>)>,
+) -> Result<fs::Entry, ParseError> {
+    let mut iter = session.into_iter();
+    let (line, first) = iter
+        .next()
+        .ok_or_else(|| ParseError::new("expected the session to start with `$ cd /`"))?;
     if first != Input::CdRoot {
-        return Err("Expected the first input to be `cd /`");
+        return Err(ParseError::expected("`$ cd /`", line));
     }
 
     let root = fs::Directory::new(String::from("/"));
     let mut stack: Vec<fs::Directory> = vec![root];
 
-    for item in iter {
+    for (line, item) in iter {
         match item {
-            Input::CdRoot => return Err("Unexpected second `cd /`"),
+            Input::CdRoot => {
+                return Err(ParseError::new("unexpected second `cd /`").within(line));
+            }
             Input::CdUp => {
                 let directory = stack.pop().expect("expected that we were in a directory");
-                let parent = stack.last_mut().ok_or("Can't cd .. when in `/`")?;
+                let parent = stack
+                    .last_mut()
+                    .ok_or_else(|| ParseError::new("can't `cd ..` out of `/`").within(line))?;
                 parent.push_child(directory.into());
             }
             Input::CdDown { name } => {
@@ -58,7 +77,7 @@ pub fn build_fs(input: Vec<Input<'_>>) -> Result<fs::Entry, &'static str> {
             }
             Input::FileLine { size, name } => {
                 let file = fs::File::new(name.to_string(), size);
-                let cwd = stack.last_mut().ok_or("Couldn't get cwd")?;
+                let cwd = stack.last_mut().expect("`/` is never left");
                 cwd.push_child(file.into());
             }
             Input::DirectoryLine { .. } => {
@@ -84,13 +103,11 @@ pub fn build_fs(input: Vec<Input<'_>>) -> Result<fs::Entry, &'static str> {
     unreachable!()
 }
 
-pub fn parse_input(input: &str) -> Result<fs::Entry, &'static str> {
-    let input = input
-        .trim()
-        .lines()
-        .map(|line| Input::from_str(line))
-        .collect::<Result<Vec<Input<'_>>, &'static str>>()?;
-    build_fs(input)
+/// Parse a terminal session and rebuild the file system from it.
+pub fn parse_input(input: &str) -> Result<fs::Entry, ParseError> {
+    let input = input.trim();
+    let session = parse_lines(input, Input::parse)?;
+    build_fs(input.lines().zip(session))
 }
 
 #[cfg(test)]
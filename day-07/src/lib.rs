@@ -0,0 +1,137 @@
+//! Day 7: No Space Left On Device.
+
+pub mod fs;
+pub mod generate;
+pub mod input;
+
+use aoc_core::{random::Rng, Day, ParseError};
+
+/// No Space Left On Device: rebuild the file system from a terminal session and size its
+/// directories.
+pub struct Day07;
+
+impl Day for Day07 {
+    const DAY: u8 = 7;
+    type Input<'a> = fs::Entry;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        input::parse_input(input)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>) -> Self::Part1 {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>) -> Self::Part2 {
+        part_2(input)
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+}
+
+/// The sum of the sizes of every directory that is at most 100000 in size.
+pub fn part_1(input: &fs::Entry) -> usize {
+    struct PartOneVisitor {
+        current_directory: usize,
+        stack: Vec<usize>,
+        total: usize,
+    }
+
+    impl fs::Visitor for PartOneVisitor {
+        fn visit_directory_before(&mut self, _pointer: &fs::Pointer, _directory: &fs::Directory) {
+            self.stack.push(self.current_directory);
+            self.current_directory = 0;
+        }
+        fn visit_directory_after(&mut self, _pointer: &fs::Pointer, _directory: &fs::Directory) {
+            let size = self.current_directory;
+            self.current_directory = self.stack.pop().unwrap_or(0);
+            self.current_directory += size;
+            if size <= 100_000 {
+                self.total += size;
+            }
+        }
+        fn visit_file(&mut self, _pointer: &fs::Pointer, file: &fs::File) {
+            self.current_directory += file.size();
+        }
+    }
+
+    let mut visitor = PartOneVisitor {
+        current_directory: 0,
+        stack: Vec::new(),
+        total: 0,
+    };
+
+    input.visit(&mut visitor);
+
+    visitor.total
+}
+
+/// The size of the smallest directory that, once deleted, frees enough space for the update.
+pub fn part_2(input: &fs::Entry) -> usize {
+    #[derive(Debug)]
+    struct PartTwoVisitor {
+        current_directory: usize,
+        stack: Vec<usize>,
+        options: Vec<(fs::Pointer, usize)>,
+    }
+
+    impl fs::Visitor for PartTwoVisitor {
+        fn visit_directory_before(&mut self, _pointer: &fs::Pointer, _directory: &fs::Directory) {
+            self.stack.push(self.current_directory);
+            self.current_directory = 0;
+        }
+        fn visit_directory_after(&mut self, pointer: &fs::Pointer, _directory: &fs::Directory) {
+            let size = self.current_directory;
+            self.current_directory = self.stack.pop().unwrap_or(0);
+            self.current_directory += size;
+            self.options.push((pointer.clone(), size));
+        }
+        fn visit_file(&mut self, _pointer: &fs::Pointer, file: &fs::File) {
+            self.current_directory += file.size();
+        }
+    }
+
+    let mut visitor = PartTwoVisitor {
+        current_directory: 0,
+        stack: Vec::new(),
+        options: Vec::new(),
+    };
+
+    input.visit(&mut visitor);
+
+    const DISK_SIZE: usize = 70_000_000;
+    const REQUIRED_SPACE: usize = 30_000_000;
+
+    let used = visitor.current_directory;
+    let free = DISK_SIZE - used;
+    if free > REQUIRED_SPACE {
+        return 0;
+    }
+    let need_to_free = REQUIRED_SPACE - free;
+
+    visitor.options.sort_by_key(|(_name, size)| *size);
+
+    let (_name, size) = visitor
+        .options
+        .iter()
+        .find(|(_name, size)| *size >= need_to_free)
+        .expect("expected at least one directory");
+
+    // println!("{name} {size}");
+
+    *size
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_core::example_tests! {
+        test_part_1: Day07, "example.txt", part_1 => 95437;
+        test_part_2: Day07, "example.txt", part_2 => 24933642;
+    }
+}
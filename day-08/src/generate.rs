@@ -0,0 +1,31 @@
+//! Random forests of tree heights.
+
+use aoc_core::random::Rng;
+
+/// A square of tree heights up to `size` on each side.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let side = 1 + rng.below(size.max(1));
+    let mut input = String::new();
+    for _ in 0..side {
+        for _ in 0..side {
+            input.push((b'0' + rng.below(10) as u8) as char);
+        }
+        input.push('\n');
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day08;
+    use aoc_core::Day;
+
+    #[test]
+    fn test_parses() {
+        for seed in 0..20 {
+            let input = input(&mut Rng::new(seed), 10);
+            assert!(Day08.parse(&input).is_ok(), "seed {seed}:\n{input}");
+        }
+    }
+}
@@ -0,0 +1,154 @@
+//! Day 8: Treetop Tree House.
+
+pub mod generate;
+
+use aoc_core::{random::Rng, Day, ParseError};
+use geometry::{Direction, Point};
+use grid::Grid;
+
+/// Treetop Tree House: count the trees visible from outside the forest and find the best spot
+/// for a tree house.
+pub struct Day08;
+
+impl Day for Day08 {
+    const DAY: u8 = 8;
+    type Input<'a> = Naive;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        Naive::parse(input.trim())
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>) -> Self::Part1 {
+        input.num_visible()
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>) -> Self::Part2 {
+        input.best_scenic_score()
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+}
+
+/// A square forest of tree heights, checked by looking in every direction from every tree.
+pub struct Naive {
+    trees: Grid<u8>,
+}
+
+impl Naive {
+    /// Parse a square of digits, one row of trees per line.
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let trees = Grid::parse_from_chars(input, |_, c| {
+            c.to_digit(10)
+                .map(|height| height as u8)
+                .ok_or("expected a tree height from `0` to `9`")
+        })?;
+        if trees.width() != trees.height() {
+            return Err(ParseError::new(format!(
+                "expected the forest to be square, but it is {} wide and {} tall",
+                trees.width(),
+                trees.height()
+            )));
+        }
+        Ok(Self { trees })
+    }
+
+    /// The width (and height) of the forest.
+    pub fn len(&self) -> usize {
+        self.trees.width()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn height_at_point(&self, x: usize, y: usize) -> u8 {
+        self.trees[Self::point(x, y)]
+    }
+
+    fn point(x: usize, y: usize) -> Point {
+        Point::new(x as i32, y as i32)
+    }
+
+    /// Whether the tree at `(x, y)` can be seen from any edge of the forest.
+    pub fn is_visible(&self, x: usize, y: usize) -> bool {
+        let point = Self::point(x, y);
+        let height = self.trees[point];
+        Direction::CARDINAL.into_iter().any(|direction| {
+            self.trees
+                .ray(point, direction)
+                .all(|(_, tree)| *tree < height)
+        })
+    }
+
+    /// How many trees can be seen from outside the forest.
+    pub fn num_visible(&self) -> usize {
+        let mut count = 0;
+        for x in 0..self.len() {
+            for y in 0..self.len() {
+                if self.is_visible(x, y) {
+                    count += 1;
+                }
+            }
+        }
+        count
+    }
+
+    /// The product of how far you can see in each direction from the tree at `(x, y)`.
+    pub fn scenic_score(&self, x: usize, y: usize) -> usize {
+        let point = Self::point(x, y);
+        let height = self.trees[point];
+        Direction::CARDINAL
+            .into_iter()
+            .map(|direction| {
+                let mut score = 0;
+                for (_, tree) in self.trees.ray(point, direction) {
+                    score += 1;
+                    if *tree >= height {
+                        break;
+                    }
+                }
+                score
+            })
+            .product()
+    }
+
+    /// The highest scenic score of any tree.
+    pub fn best_scenic_score(&self) -> usize {
+        let mut best = 0;
+        for x in 0..self.len() {
+            for y in 0..self.len() {
+                let current = self.scenic_score(x, y);
+                if current > best {
+                    best = current
+                }
+            }
+        }
+        best
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scenic_score() {
+        let input = include_str!("example.txt");
+        let naive = Naive::parse(input.trim()).unwrap();
+
+        let first_example = naive.scenic_score(2, 1);
+        assert_eq!(first_example, 4);
+
+        let second_example = naive.scenic_score(2, 3);
+        assert_eq!(second_example, 8);
+    }
+
+    aoc_core::example_tests! {
+        test_part_1: Day08, "example.txt", part_1 => 21;
+        test_part_2: Day08, "example.txt", part_2 => 8;
+    }
+}
@@ -0,0 +1,28 @@
+//! Random motions for the head of the rope.
+
+use aoc_core::random::Rng;
+
+/// `size` motions of up to 20 steps each.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size.max(1) {
+        let direction = rng.choose(&['U', 'D', 'L', 'R']);
+        input.push_str(&format!("{direction} {}\n", rng.range(1..=20)));
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day09;
+    use aoc_core::Day;
+
+    #[test]
+    fn test_parses() {
+        for seed in 0..20 {
+            let input = input(&mut Rng::new(seed), 10);
+            assert!(Day09.parse(&input).is_ok(), "seed {seed}:\n{input}");
+        }
+    }
+}
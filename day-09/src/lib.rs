@@ -0,0 +1,302 @@
+//! Day 9: Rope Bridge.
+
+pub mod generate;
+pub mod vis;
+
+use aoc_core::{
+    parse_lines,
+    random::Rng,
+    stream::{self, StreamError},
+    Day, ParseError, StreamingDay,
+};
+use geometry::{Direction, Point};
+use std::{collections::HashSet, io::BufRead, str::FromStr};
+
+/// Rope Bridge: follow the knots of a rope as its head is dragged around.
+pub struct Day09;
+
+impl Day for Day09 {
+    const DAY: u8 = 9;
+    type Input<'a> = Vec<Command>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        Command::parse(input)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>) -> Self::Part1 {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>) -> Self::Part2 {
+        part_2(input)
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+}
+
+/// Both ropes are pulled along as each motion is read, so only the positions their tails visit are
+/// kept.
+impl StreamingDay for Day09 {
+    fn solve_stream(&self, reader: impl BufRead) -> Result<(usize, usize), StreamError> {
+        let mut short = Rope::new(2);
+        let mut long = Rope::new(10);
+        let lines = stream::parse_lines(reader, |line| {
+            (!line.is_empty()).then(|| line.parse()).transpose()
+        });
+        for command in lines {
+            if let Some(command) = command? {
+                short.pull(command);
+                long.pull(command);
+            }
+        }
+        Ok((short.tail_positions(), long.tail_positions()))
+    }
+}
+
+/// How many positions the tail of a two-knot rope visits at least once.
+pub fn part_1(commands: &[Command]) -> usize {
+    let mut head = Head(Position::default());
+    let mut tail = Tail(Position::default());
+
+    let mut tail_positions = HashSet::new();
+    for command in commands {
+        let direction = command.direction();
+        for _ in 0..command.count() {
+            *head.position_mut() = head.position().step(direction);
+            tail.move_toward_head(&head);
+            tail_positions.insert(tail.position());
+        }
+    }
+    tail_positions.len()
+}
+
+/// How many positions the tail of a ten-knot rope visits at least once.
+pub fn part_2(commands: &[Command]) -> usize {
+    let mut head = Head(Position::default());
+    let mut tails = [Tail(Position::default()); 9];
+
+    let mut tail_positions = HashSet::new();
+    for command in commands {
+        let direction = command.direction();
+        for _ in 0..command.count() {
+            *head.position_mut() = head.position().step(direction);
+            tails[0].move_toward_position(head.position());
+            for i in 1..tails.len() {
+                let previous_position = tails[i - 1].position();
+                tails[i].move_toward_position(previous_position);
+            }
+            tail_positions.insert(tails.last().unwrap().position());
+        }
+    }
+    tail_positions.len()
+}
+
+/// A rope of any length, keeping track of where its last knot has been.
+struct Rope {
+    head: Head,
+    tails: Vec<Tail>,
+    tail_positions: HashSet<Position>,
+}
+
+impl Rope {
+    /// A rope with `knots` knots, counting the head, all starting at the origin.
+    fn new(knots: usize) -> Self {
+        Self {
+            head: Head::default(),
+            tails: vec![Tail::default(); knots - 1],
+            tail_positions: HashSet::new(),
+        }
+    }
+
+    fn pull(&mut self, command: Command) {
+        let direction = command.direction();
+        for _ in 0..command.count() {
+            *self.head.position_mut() = self.head.position().step(direction);
+            let mut previous_position = self.head.position();
+            for tail in &mut self.tails {
+                tail.move_toward_position(previous_position);
+                previous_position = tail.position();
+            }
+            self.tail_positions.insert(previous_position);
+        }
+    }
+
+    /// How many positions the last knot has visited.
+    fn tail_positions(&self) -> usize {
+        self.tail_positions.len()
+    }
+}
+
+/// Where a knot of the rope is. The rope moves on an unbounded plane.
+type Position = Point<isize>;
+
+#[derive(Debug, Default)]
+struct Head(Position);
+
+impl Head {
+    pub fn position(&self) -> Position {
+        self.0
+    }
+
+    pub fn position_mut(&mut self) -> &mut Position {
+        &mut self.0
+    }
+}
+
+#[derive(Debug, Default, Copy, Clone)]
+struct Tail(Position);
+
+/// One motion of the head, like `R 4`.
+#[derive(Debug, Copy, Clone)]
+pub enum Command {
+    Left(usize),
+    Right(usize),
+    Up(usize),
+    Down(usize),
+}
+
+impl FromStr for Command {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let steps = |num: &str| {
+            num.parse()
+                .map_err(|_| ParseError::expected("a number of steps", num))
+        };
+        if let Some(num) = s.strip_prefix("U ") {
+            Ok(Self::Up(steps(num)?))
+        } else if let Some(num) = s.strip_prefix("D ") {
+            Ok(Self::Down(steps(num)?))
+        } else if let Some(num) = s.strip_prefix("L ") {
+            Ok(Self::Left(steps(num)?))
+        } else if let Some(num) = s.strip_prefix("R ") {
+            Ok(Self::Right(steps(num)?))
+        } else {
+            Err(ParseError::expected(
+                "a motion starting with `U`, `D`, `L` or `R`",
+                s,
+            ))
+        }
+    }
+}
+
+impl Command {
+    /// How many steps the head takes.
+    pub fn count(&self) -> usize {
+        match self {
+            Command::Left(v) => *v,
+            Command::Right(v) => *v,
+            Command::Up(v) => *v,
+            Command::Down(v) => *v,
+        }
+    }
+
+    /// Which way the head moves.
+    pub fn direction(&self) -> Direction {
+        match self {
+            Command::Left(_) => Direction::West,
+            Command::Right(_) => Direction::East,
+            Command::Up(_) => Direction::North,
+            Command::Down(_) => Direction::South,
+        }
+    }
+
+    /// Parse one motion per line.
+    pub fn parse(input: &str) -> Result<Vec<Command>, ParseError> {
+        parse_lines(input.trim(), str::parse)
+    }
+}
+
+impl Tail {
+    pub fn move_toward_head(&mut self, head: &Head) {
+        self.move_toward_position(head.position())
+    }
+
+    pub fn move_toward_position(&mut self, position: Position) {
+        let relative = position - self.position();
+        if relative.chebyshev_length() <= 1 {
+            return;
+        }
+
+        self.0 += relative.signum();
+    }
+
+    pub fn position(&self) -> Position {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vector_addition() {
+        let position1 = Position { x: 7, y: 7 };
+        let position2 = Position { x: 9, y: 9 };
+        let vector = position2 - position1;
+        let final_position = position1 + vector;
+        assert_eq!(final_position, position2);
+    }
+
+    #[test]
+    fn move_toward_head() {
+        let head = Head(Position { x: 7, y: 7 });
+        let mut tail = Tail(Position { x: 7, y: 7 });
+        tail.move_toward_head(&head);
+        assert_eq!(tail.position(), Position { x: 7, y: 7 });
+
+        let head = Head(Position { x: 8, y: 7 });
+        let mut tail = Tail(Position { x: 7, y: 7 });
+        tail.move_toward_head(&head);
+        assert_eq!(tail.position(), Position { x: 7, y: 7 });
+
+        let head = Head(Position { x: 7, y: 8 });
+        let mut tail = Tail(Position { x: 7, y: 7 });
+        tail.move_toward_head(&head);
+        assert_eq!(tail.position(), Position { x: 7, y: 7 });
+
+        let head = Head(Position { x: 8, y: 8 });
+        let mut tail = Tail(Position { x: 7, y: 7 });
+        tail.move_toward_head(&head);
+        assert_eq!(tail.position(), Position { x: 7, y: 7 });
+
+        let head = Head(Position { x: 7, y: 9 });
+        let mut tail = Tail(Position { x: 7, y: 7 });
+        tail.move_toward_head(&head);
+        assert_eq!(tail.position(), Position { x: 7, y: 8 });
+
+        let head = Head(Position { x: 9, y: 7 });
+        let mut tail = Tail(Position { x: 7, y: 7 });
+        tail.move_toward_head(&head);
+        assert_eq!(tail.position(), Position { x: 8, y: 7 });
+
+        let head = Head(Position { x: 9, y: 8 });
+        let mut tail = Tail(Position { x: 7, y: 7 });
+        tail.move_toward_head(&head);
+        assert_eq!(tail.position(), Position { x: 8, y: 8 });
+
+        let head = Head(Position { x: 8, y: 9 });
+        let mut tail = Tail(Position { x: 7, y: 7 });
+        tail.move_toward_head(&head);
+        assert_eq!(tail.position(), Position { x: 8, y: 8 });
+    }
+
+    #[test]
+    fn test_solve_stream() {
+        let input = include_str!("example2.txt");
+        let commands = Command::parse(input).unwrap();
+        let answers = Day09.solve_stream(input.as_bytes()).unwrap();
+        assert_eq!(answers, (part_1(&commands), 36));
+    }
+
+    aoc_core::example_tests! {
+        test_part_1: Day09, "example.txt", part_1 => 13;
+        test_part_2: Day09, "example.txt", part_2 => 1;
+        test_part_2_larger: Day09, "example2.txt", part_2 => 36;
+    }
+}
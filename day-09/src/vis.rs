@@ -0,0 +1,123 @@
+//! An animation of the ten-knot rope from part 2, following the head around.
+
+use super::{Command, Head, Position, Tail};
+use geometry::Point;
+use std::collections::HashSet;
+use vis::{Canvas, Viewport, Visualize};
+
+pub struct Visualization {
+    commands: Vec<Command>,
+    /// How many of the commands have been started.
+    started: usize,
+    /// How many steps of the current command are left to take.
+    remaining: usize,
+    head: Head,
+    tails: [Tail; 9],
+    visited: HashSet<Position>,
+}
+
+impl Visualization {
+    pub fn new(commands: Vec<Command>) -> Self {
+        Self {
+            commands,
+            started: 0,
+            remaining: 0,
+            head: Head::default(),
+            tails: [Tail::default(); 9],
+            visited: HashSet::from([Position::default()]),
+        }
+    }
+
+    /// How many positions the last knot has visited so far.
+    pub fn visited(&self) -> usize {
+        self.visited.len()
+    }
+
+    fn finished(&self) -> bool {
+        self.remaining == 0 && self.started == self.commands.len()
+    }
+
+    /// Move the head one step, starting the next command if the last one is done.
+    fn step(&mut self) {
+        while self.remaining == 0 {
+            let Some(command) = self.commands.get(self.started) else {
+                return;
+            };
+            self.remaining = command.count();
+            self.started += 1;
+        }
+        let direction = self.commands[self.started - 1].direction();
+        *self.head.position_mut() = self.head.position().step(direction);
+        self.tails[0].move_toward_position(self.head.position());
+        for i in 1..self.tails.len() {
+            let previous_position = self.tails[i - 1].position();
+            self.tails[i].move_toward_position(previous_position);
+        }
+        self.visited.insert(self.tails[8].position());
+        self.remaining -= 1;
+    }
+}
+
+/// Where the rope is in the plane the screen shows.
+fn screen_point(position: Position) -> Point {
+    Point::new(position.x as i32, position.y as i32)
+}
+
+impl Visualize for Visualization {
+    /// Every step moves the head by one; otherwise it makes the whole of its next motion.
+    fn tick(&mut self, every_step: bool) -> bool {
+        self.step();
+        while !every_step && self.remaining > 0 {
+            self.step();
+        }
+        self.finished()
+    }
+
+    fn draw(&self, canvas: &Canvas) {
+        canvas.clear();
+        let viewport = Viewport::around(screen_point(self.head.position()));
+
+        canvas.dark_green();
+        for &position in &self.visited {
+            if let Some((x, y)) = viewport.to_screen(screen_point(position)) {
+                canvas.fill_box(x, y);
+            }
+        }
+
+        // The tail is drawn first so that knots nearer the head cover it.
+        canvas.bright_green();
+        for (knot, tail) in self.tails.iter().enumerate().rev() {
+            if let Some((x, y)) = viewport.to_screen(screen_point(tail.position())) {
+                canvas.draw_byte(x, y, b'1' + knot as u8);
+            }
+        }
+        canvas.bright_red();
+        if let Some((x, y)) = viewport.to_screen(screen_point(self.head.position())) {
+            canvas.draw_byte(x, y, b'H');
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_visits_as_many_as_part_2() {
+        let input = include_str!("example2.txt");
+        let commands = Command::parse(input).unwrap();
+        let expected = crate::part_2(&commands);
+
+        let mut vis = Visualization::new(commands.clone());
+        let mut ticks = 0;
+        while !vis.tick(false) {
+            ticks += 1;
+        }
+        assert_eq!(ticks + 1, commands.len());
+        assert_eq!(vis.visited(), expected);
+
+        let mut vis = Visualization::new(commands.clone());
+        while !vis.tick(true) {}
+        assert_eq!(vis.visited(), expected);
+    }
+}
@@ -0,0 +1,36 @@
+//! Random programs for the handheld's CPU.
+
+use aoc_core::random::Rng;
+
+/// A program that runs for at least the 240 cycles the screen needs, and longer for bigger
+/// `size`s.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let cycles = 240.max(10 * size);
+    let mut input = String::new();
+    let mut cycle = 0;
+    while cycle < cycles {
+        if rng.chance(0.4) {
+            input.push_str("noop\n");
+            cycle += 1;
+        } else {
+            input.push_str(&format!("addx {}\n", rng.range(-40..=40)));
+            cycle += 2;
+        }
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day10;
+    use aoc_core::Day;
+
+    #[test]
+    fn test_parses() {
+        for seed in 0..20 {
+            let input = input(&mut Rng::new(seed), 10);
+            assert!(Day10.parse(&input).is_ok(), "seed {seed}:\n{input}");
+        }
+    }
+}
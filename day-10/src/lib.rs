@@ -0,0 +1,410 @@
+//! Day 10: Cathode-Ray Tube.
+
+pub mod generate;
+
+use aoc_core::{
+    json::{self, ToJson},
+    parse_lines,
+    random::Rng,
+    stream::{self, StreamError},
+    Day, ParseError, StreamingDay,
+};
+use std::{
+    borrow::Borrow,
+    fmt::{Debug, Display},
+    io::BufRead,
+    str::FromStr,
+};
+
+/// Cathode-Ray Tube: run the handheld's CPU and draw what its screen shows.
+pub struct Day10;
+
+impl Day for Day10 {
+    const DAY: u8 = 10;
+    type Input<'a> = Vec<Instruction>;
+    type Part1 = isize;
+    type Part2 = Crt;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>) -> Self::Part1 {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>) -> Self::Part2 {
+        part_2(input)
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+}
+
+/// The program runs as it's read, one instruction at a time, so it never has to fit in memory.
+impl StreamingDay for Day10 {
+    fn solve_stream(&self, reader: impl BufRead) -> Result<(isize, Crt), StreamError> {
+        let mut error = None;
+        let instructions = stream::parse_lines(reader, str::parse::<Instruction>)
+            .map_while(|instruction| instruction.map_err(|err| error = Some(err)).ok());
+
+        let mut signal_strength = 0;
+        let mut crt = Crt::default();
+        for tick in Computer::new(instructions) {
+            if tick.cycle % 40 == 20 && tick.cycle <= 220 {
+                signal_strength += tick.signal_strength();
+            }
+            crt.set(tick.crt_row(), tick.crt_column(), tick.in_sprite());
+        }
+        match error {
+            Some(err) => Err(err),
+            None => Ok((signal_strength, crt)),
+        }
+    }
+}
+
+/// Parse one instruction per line.
+pub fn parse_input(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    parse_lines(input, str::parse)
+}
+
+/// The sum of the signal strengths during the 20th, 60th, ... 220th cycles.
+pub fn part_1(input: &[Instruction]) -> isize {
+    let computer = Computer::new(input);
+    computer
+        .filter(|tick| tick.cycle % 40 == 20 && tick.cycle <= 220)
+        .map(|tick| tick.signal_strength())
+        .sum()
+}
+
+/// The picture drawn on the CRT while the program runs.
+pub fn part_2(input: &[Instruction]) -> Crt {
+    let computer = Computer::new(input);
+    let mut crt = Crt::default();
+    for tick in computer {
+        crt.set(tick.crt_row(), tick.crt_column(), tick.in_sprite());
+    }
+    crt
+}
+
+/// One CPU instruction: `addx V` or `noop`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Instruction {
+    AddX(isize),
+    Noop,
+}
+
+impl Instruction {
+    fn cycle_count(&self) -> isize {
+        match self {
+            Instruction::AddX(_) => 2,
+            Instruction::Noop => 1,
+        }
+    }
+
+    fn apply(&self, value: &mut isize) {
+        match self {
+            Instruction::AddX(v) => *value += v,
+            Instruction::Noop => {}
+        }
+    }
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if let Some(rest) = s.strip_prefix("addx ") {
+            let value = rest
+                .parse()
+                .map_err(|_| ParseError::expected("a number to add", rest))?;
+            Ok(Instruction::AddX(value))
+        } else if s == "noop" {
+            Ok(Instruction::Noop)
+        } else {
+            Err(ParseError::expected("`addx <n>` or `noop`", s))
+        }
+    }
+}
+
+/// Runs a program, taking each instruction from `instructions` only once the one before it is done.
+struct Computer<I> {
+    instructions: I,
+    /// The instruction being run, or `None` once the program has ended.
+    current: Option<Instruction>,
+    instruction_pointer: usize,
+    instruction_cycle_number: isize,
+    cycle: isize,
+    value: isize,
+}
+
+impl<I> Debug for Computer<I> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Computer")
+            .field("current", &self.current)
+            .field("instruction_pointer", &self.instruction_pointer)
+            .field("instruction_cycle_number", &self.instruction_cycle_number)
+            .field("cycle", &self.cycle)
+            .field("value", &self.value)
+            .finish()
+    }
+}
+
+impl<I> Computer<I>
+where
+    I: Iterator,
+    I::Item: Borrow<Instruction>,
+{
+    pub fn new(instructions: impl IntoIterator<IntoIter = I>) -> Self {
+        let mut instructions = instructions.into_iter();
+        let current = instructions.next().map(|instruction| *instruction.borrow());
+        Self {
+            instructions,
+            current,
+            instruction_pointer: 0,
+            instruction_cycle_number: 0,
+            cycle: 1,
+            value: 1,
+        }
+    }
+
+    pub fn tick(&mut self) -> Option<Tick> {
+        let instruction = self.current?;
+        let cycle_count = instruction.cycle_count();
+        let current_value = self.value;
+        let current_cycle = self.cycle;
+        self.instruction_cycle_number += 1;
+        if self.instruction_cycle_number >= cycle_count {
+            instruction.apply(&mut self.value);
+            self.instruction_cycle_number = 0;
+            self.instruction_pointer += 1;
+            self.current = self
+                .instructions
+                .next()
+                .map(|instruction| *instruction.borrow());
+        }
+        self.cycle += 1;
+
+        Some(Tick {
+            value: current_value,
+            cycle: current_cycle,
+        })
+    }
+}
+
+impl<I> Iterator for Computer<I>
+where
+    I: Iterator,
+    I::Item: Borrow<Instruction>,
+{
+    type Item = Tick;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.tick()
+    }
+}
+
+/// The state of the computer *during* a cycle
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+struct Tick {
+    cycle: isize,
+    value: isize,
+}
+
+impl Tick {
+    fn signal_strength(&self) -> isize {
+        self.cycle * self.value
+    }
+
+    fn crt_row(&self) -> isize {
+        (self.cycle - 1) / 40
+    }
+
+    fn crt_column(&self) -> isize {
+        (self.cycle - 1) % 40
+    }
+
+    fn in_sprite(&self) -> bool {
+        let column = self.crt_column();
+        self.value - 1 <= column && column <= self.value + 1
+    }
+}
+
+/// The 40 by 6 screen. Lit pixels display as `#` and dark ones as `.`.
+pub struct Crt {
+    pixels: [[bool; 40]; 6],
+}
+
+impl Crt {
+    /// Whether the pixel at `row` and `column` is lit.
+    pub fn is_lit(&self, row: usize, column: usize) -> bool {
+        self.pixels[row][column]
+    }
+
+    fn set(&mut self, row: isize, column: isize, value: bool) {
+        if !(0..6).contains(&row) || !(0..40).contains(&column) {
+            return;
+        }
+        self.pixels[row as usize][column as usize] = value;
+    }
+}
+
+impl Default for Crt {
+    fn default() -> Self {
+        let pixels = [
+            [false; 40],
+            [false; 40],
+            [false; 40],
+            [false; 40],
+            [false; 40],
+            [false; 40],
+        ];
+        Self { pixels }
+    }
+}
+
+/// The picture as rows of pixels, with `true` for the lit ones.
+impl ToJson for Crt {
+    fn to_json(&self) -> String {
+        json::array(self.pixels.iter().map(|row| row.to_json()))
+    }
+}
+
+impl Display for Crt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for y in 0..6 {
+            for x in 0..40 {
+                if self.pixels[y][x] {
+                    f.write_str("#")?;
+                } else {
+                    f.write_str(".")?;
+                }
+            }
+            f.write_str("\n")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_basic() {
+        let input = r#"
+        noop
+        addx 3
+        addx -5        
+        "#;
+
+        let instructions = input
+            .trim()
+            .lines()
+            .map(|line| line.parse::<Instruction>())
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        let mut computer = Computer::new(&instructions);
+        println!("{computer:?}");
+        let tick = computer.tick().unwrap();
+        assert_eq!(tick.cycle, 1);
+        assert_eq!(tick.value, 1);
+
+        println!("{computer:?}");
+        let tick = computer.tick().unwrap();
+        assert_eq!(tick.cycle, 2);
+        assert_eq!(tick.value, 1);
+
+        println!("{computer:?}");
+        let tick = computer.tick().unwrap();
+        assert_eq!(tick.cycle, 3);
+        assert_eq!(tick.value, 1);
+
+        println!("{computer:?}");
+        let tick = computer.tick().unwrap();
+        assert_eq!(tick.cycle, 4);
+        assert_eq!(tick.value, 4);
+
+        println!("{computer:?}");
+        let tick = computer.tick().unwrap();
+        assert_eq!(tick.cycle, 5);
+        assert_eq!(tick.value, 4);
+
+        println!("{computer:?}");
+
+        assert_eq!(computer.tick(), None);
+    }
+
+    #[test]
+    fn examples() {
+        let input = include_str!("example.txt");
+        let input = input
+            .trim()
+            .lines()
+            .map(|line| line.parse::<Instruction>())
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        let computer = Computer::new(&input);
+        for tick in computer {
+            if tick.cycle == 20 {
+                assert_eq!(tick.value, 21);
+            }
+            if tick.cycle == 60 {
+                assert_eq!(tick.value, 19);
+            }
+            if tick.cycle == 100 {
+                assert_eq!(tick.value, 18);
+            }
+            if tick.cycle == 140 {
+                assert_eq!(tick.value, 21);
+            }
+            if tick.cycle == 180 {
+                assert_eq!(tick.value, 16);
+            }
+            if tick.cycle == 220 {
+                assert_eq!(tick.value, 18);
+            }
+        }
+    }
+
+    #[test]
+    fn test_to_json() {
+        let mut crt = Crt::default();
+        crt.set(0, 1, true);
+        crt.set(5, 39, true);
+        let json = crt.to_json();
+        assert!(json.starts_with("[[false,true,false,"));
+        assert!(json.ends_with(",false,true]]"));
+        assert_eq!(json.matches("true").count(), 2);
+        assert_eq!(json.matches('[').count(), 7);
+    }
+
+    #[test]
+    fn test_solve_stream() {
+        let input = include_str!("example.txt");
+        let (part_1, part_2) = Day10.solve_stream(input.as_bytes()).unwrap();
+        assert_eq!(part_1, 13140);
+        assert_eq!(part_2.to_string(), CRT_EXAMPLE);
+
+        let err = Day10.solve_stream("noop\naddx\n".as_bytes()).err();
+        assert!(matches!(err, Some(StreamError::Parse(_))));
+    }
+
+    const CRT_EXAMPLE: &str = "\
+##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######.....
+";
+
+    aoc_core::example_tests! {
+        test_part_1: Day10, "example.txt", part_1 => 13140;
+        test_part_2: Day10, "example.txt", part_2 => CRT_EXAMPLE;
+    }
+}
@@ -0,0 +1,63 @@
+//! Random notes on the monkeys. Like the real notes, every monkey tests a different prime, so
+//! part two can keep worry levels down by the product of the primes.
+
+use aoc_core::random::Rng;
+
+const PRIMES: [i64; 9] = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+
+/// Three to nine monkeys, holding up to `size` items each.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let count = 3 + rng.below(size.clamp(1, 7));
+    let mut primes = PRIMES;
+    rng.shuffle(&mut primes);
+    let square = rng.below(count);
+
+    let mut monkeys = Vec::with_capacity(count);
+    for (monkey, prime) in primes.into_iter().enumerate().take(count) {
+        let items = (0..1 + rng.below(size.max(1)))
+            .map(|_| rng.range(50..=99).to_string())
+            .collect::<Vec<_>>();
+        let operation = if monkey == square {
+            String::from("old * old")
+        } else if rng.chance(0.5) {
+            format!("old * {}", rng.range(2..=19))
+        } else {
+            format!("old + {}", rng.range(1..=8))
+        };
+        let if_true = other(rng, count, &[monkey]);
+        let if_false = other(rng, count, &[monkey, if_true]);
+        monkeys.push(format!(
+            "Monkey {monkey}:
+  Starting items: {}
+  Operation: new = {operation}
+  Test: divisible by {prime}
+    If true: throw to monkey {if_true}
+    If false: throw to monkey {if_false}
+",
+            items.join(", ")
+        ));
+    }
+    monkeys.join("\n")
+}
+
+/// A monkey other than the ones in `not`.
+fn other(rng: &mut Rng, count: usize, not: &[usize]) -> usize {
+    let others = (0..count)
+        .filter(|monkey| !not.contains(monkey))
+        .collect::<Vec<_>>();
+    *rng.choose(&others)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_input;
+
+    #[test]
+    fn test_parses() {
+        for seed in 0..20 {
+            let input = input(&mut Rng::new(seed), 10);
+            assert!(parse_input(&input).is_ok(), "seed {seed}:\n{input}");
+        }
+    }
+}
@@ -0,0 +1,364 @@
+//! Day 11: Monkey in the Middle.
+
+pub mod generate;
+
+use aoc_core::{random::Rng, Day, ParseError};
+use std::{collections::VecDeque, fmt::Debug, str::FromStr};
+
+/// Monkey in the Middle: track which monkeys throw your items around the most.
+pub struct Day11;
+
+impl Day for Day11 {
+    const DAY: u8 = 11;
+    type Input<'a> = Vec<Monkey>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>) -> Self::Part1 {
+        part_1(input.clone())
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>) -> Self::Part2 {
+        part_2(input.clone())
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+}
+
+/// Parse the notes on each monkey. Each monkey takes up six lines, and monkeys are separated by
+/// a blank line.
+pub fn parse_input(input: &str) -> Result<Vec<Monkey>, ParseError> {
+    let lines = input.lines().collect::<Vec<_>>();
+    lines.chunks(7).map(Monkey::parse).collect()
+}
+
+/// The level of monkey business (the product of the two highest inspection counts) after 20
+/// rounds, with your worry dropping after each inspection.
+pub fn part_1(mut monkeys: Vec<Monkey>) -> usize {
+    for _ in 0..20 {
+        round(&mut monkeys, Worry::Regular);
+    }
+
+    let mut number_inspected = monkeys
+        .iter()
+        .map(|monkey| monkey.inspected)
+        .collect::<Vec<_>>();
+    number_inspected.sort_by(|a, b| a.cmp(b).reverse());
+    number_inspected.into_iter().take(2).product()
+}
+
+/// The level of monkey business after 10000 rounds, with your worry never dropping.
+pub fn part_2(mut monkeys: Vec<Monkey>) -> usize {
+    for _ in 0..10_000 {
+        round(&mut monkeys, Worry::Extreme);
+    }
+
+    let mut number_inspected = monkeys
+        .iter()
+        .map(|monkey| monkey.inspected)
+        .collect::<Vec<_>>();
+    number_inspected.sort_by(|a, b| a.cmp(b).reverse());
+    number_inspected.into_iter().take(2).product()
+}
+
+/// Give every monkey one turn, in order.
+pub fn round(monkeys: &mut [Monkey], worry_level: Worry) {
+    for i in 0..monkeys.len() {
+        let monkey = monkeys.get_mut(i).unwrap();
+        let throws = monkey.turn(worry_level);
+        for throw in throws {
+            let other_monkey = monkeys.get_mut(throw.monkey.0).unwrap();
+            other_monkey.items.push_back(throw.item);
+        }
+    }
+}
+
+/// How worried you get about your items.
+#[derive(Copy, Clone)]
+pub enum Worry {
+    /// Your worry level divides by 3 after each inspection
+    Regular,
+    /// Your worry level does not divide by 3 after each inspection
+    Extreme,
+}
+
+/// A monkey, the items it holds, and how it decides where to throw them.
+#[derive(Clone)]
+pub struct Monkey {
+    items: VecDeque<Item>,
+    operation: Operation,
+    test: i64,
+    if_true: OtherMonkey,
+    if_false: OtherMonkey,
+    inspected: usize,
+}
+
+impl Monkey {
+    /// How many items this monkey has inspected so far.
+    pub fn inspected(&self) -> usize {
+        self.inspected
+    }
+
+    /// Inspect and throw every item the monkey is holding.
+    pub fn turn(&mut self, worry_level: Worry) -> Vec<Throw> {
+        let mut throws = Vec::with_capacity(self.items.len());
+        while let Some(throw) = self.throw(worry_level) {
+            throws.push(throw);
+        }
+        throws
+    }
+
+    /// Inspect and throw the next item, if the monkey is holding any.
+    pub fn throw(&mut self, worry_level: Worry) -> Option<Throw> {
+        let item = self.items.pop_front()?;
+        let mut item = self.operation.apply(item);
+        if matches!(worry_level, Worry::Regular) {
+            item = item.relief();
+        } else {
+            item = item.manageable();
+        }
+        let monkey = if item.0 % self.test == 0 {
+            self.if_true
+        } else {
+            self.if_false
+        };
+        self.inspected += 1;
+        Some(Throw { item, monkey })
+    }
+
+    fn parse(lines: &[&str]) -> Result<Self, ParseError> {
+        /// The rest of `line` after `prefix`, ignoring indentation.
+        fn field<'a>(line: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+            let line = line.trim();
+            line.strip_prefix(prefix)
+                .ok_or_else(|| ParseError::expected(format!("`{}`", prefix.trim_end()), line))
+        }
+
+        if lines.len() < 6 {
+            let found = lines.last().copied().unwrap_or_default();
+            return Err(ParseError::new("expected six lines describing a monkey").within(found));
+        }
+        field(lines[0], "Monkey ")?;
+        let items = field(lines[1], "Starting items: ")?;
+        let operation = field(lines[2], "Operation: new = ")?;
+        let test = field(lines[3], "Test: divisible by ")?;
+        let if_true = field(lines[4], "If true: throw to monkey ")?;
+        let if_false = field(lines[5], "If false: throw to monkey ")?;
+        let items = items
+            .split(", ")
+            .map(|val| val.parse::<Item>())
+            .collect::<Result<VecDeque<_>, _>>()?;
+        let operation = operation.parse()?;
+        let test = test
+            .parse()
+            .map_err(|_| ParseError::expected("a number to divide by", test))?;
+        let if_true = if_true.parse()?;
+        let if_false = if_false.parse()?;
+
+        Ok(Monkey {
+            items,
+            operation,
+            test,
+            if_true,
+            if_false,
+            inspected: 0,
+        })
+    }
+}
+
+/// The worry level of an item.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Item(i64);
+
+impl From<i64> for Item {
+    fn from(inner: i64) -> Self {
+        Self(inner)
+    }
+}
+
+impl FromStr for Item {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let inner = s
+            .parse::<i64>()
+            .map_err(|_| ParseError::expected("a worry level", s))?;
+        Ok(Self(inner))
+    }
+}
+
+impl Item {
+    const MOD: i64 = 2 * 3 * 5 * 7 * 11 * 13 * 17 * 19 * 23;
+
+    /// Your relief that the monkey didn't damage the item.
+    pub fn relief(self) -> Item {
+        Item(self.0 / 3)
+    }
+
+    /// Keep the worry level from growing without bound, without changing the result of any
+    /// monkey's test.
+    pub fn manageable(self) -> Item {
+        Item(self.0 % Self::MOD)
+    }
+}
+
+/// The index of the monkey an item is thrown to.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct OtherMonkey(usize);
+
+impl From<usize> for OtherMonkey {
+    fn from(inner: usize) -> Self {
+        Self(inner)
+    }
+}
+
+impl FromStr for OtherMonkey {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let inner = s
+            .parse::<usize>()
+            .map_err(|_| ParseError::expected("a monkey number", s))?;
+        Ok(Self(inner))
+    }
+}
+
+/// How an item's worry level changes when a monkey inspects it.
+#[derive(Copy, Clone)]
+pub enum Operation {
+    Add(i64),
+    Multiply(i64),
+    Square,
+}
+
+impl FromStr for Operation {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "old * old" {
+            Ok(Self::Square)
+        } else if let Some(multiply) = s.strip_prefix("old * ") {
+            let multiply = multiply
+                .parse()
+                .map_err(|_| ParseError::expected("a number to multiply by", multiply))?;
+            Ok(Self::Multiply(multiply))
+        } else if let Some(add) = s.strip_prefix("old + ") {
+            let add = add
+                .parse()
+                .map_err(|_| ParseError::expected("a number to add", add))?;
+            Ok(Self::Add(add))
+        } else {
+            Err(ParseError::expected(
+                "`old * old`, `old * <n>` or `old + <n>`",
+                s,
+            ))
+        }
+    }
+}
+
+impl Operation {
+    pub fn apply(&self, item: Item) -> Item {
+        match self {
+            Operation::Add(val) => Item(item.0 + val),
+            Operation::Multiply(val) => Item(item.0 * val),
+            Operation::Square => Item(item.0 * item.0),
+        }
+    }
+}
+
+/// An item on its way to another monkey.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Throw {
+    monkey: OtherMonkey,
+    item: Item,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn first_monkey() {
+        let mut monkey = Monkey {
+            items: VecDeque::from([Item(79), Item(98)]),
+            operation: Operation::Multiply(19),
+            test: 23,
+            if_true: OtherMonkey(2),
+            if_false: OtherMonkey(3),
+            inspected: 0,
+        };
+
+        let throws = monkey.turn(Worry::Regular);
+        assert_eq!(
+            throws,
+            vec![
+                Throw {
+                    item: Item(500),
+                    monkey: OtherMonkey(3),
+                },
+                Throw {
+                    item: Item(620),
+                    monkey: OtherMonkey(3),
+                }
+            ]
+        );
+        assert_eq!(monkey.inspected, 2);
+    }
+
+    #[test]
+    fn test_part_2_after_1_round() {
+        let mut monkeys = parse_input(include_str!("example.txt")).unwrap();
+        round(&mut monkeys, Worry::Extreme);
+        assert_eq!(monkeys[0].inspected, 2);
+        assert_eq!(monkeys[1].inspected, 4);
+        assert_eq!(monkeys[2].inspected, 3);
+        assert_eq!(monkeys[3].inspected, 6);
+    }
+
+    #[test]
+    fn test_part_2_after_20_rounds() {
+        let mut monkeys = parse_input(include_str!("example.txt")).unwrap();
+        for _ in 0..20 {
+            round(&mut monkeys, Worry::Extreme);
+        }
+        assert_eq!(monkeys[0].inspected, 99);
+        assert_eq!(monkeys[1].inspected, 97);
+        assert_eq!(monkeys[2].inspected, 8);
+        assert_eq!(monkeys[3].inspected, 103);
+    }
+
+    #[test]
+    fn test_part_2_after_1000_rounds() {
+        let mut monkeys = parse_input(include_str!("example.txt")).unwrap();
+        for _ in 0..1000 {
+            round(&mut monkeys, Worry::Extreme);
+        }
+        assert_eq!(monkeys[0].inspected, 5204);
+        assert_eq!(monkeys[1].inspected, 4792);
+        assert_eq!(monkeys[2].inspected, 199);
+        assert_eq!(monkeys[3].inspected, 5192);
+    }
+
+    #[test]
+    fn test_part_2_after_7000_rounds() {
+        let mut monkeys = parse_input(include_str!("example.txt")).unwrap();
+        for _ in 0..7_000 {
+            round(&mut monkeys, Worry::Extreme);
+        }
+        assert_eq!(monkeys[0].inspected, 36508);
+        assert_eq!(monkeys[1].inspected, 33488);
+        assert_eq!(monkeys[2].inspected, 1360);
+        assert_eq!(monkeys[3].inspected, 36400);
+    }
+
+    aoc_core::example_tests! {
+        test_part_1: Day11, "example.txt", part_1 => 10605;
+        test_part_2: Day11, "example.txt", part_2 => 2713310158u64;
+    }
+}
@@ -0,0 +1,68 @@
+//! Random heightmaps with a climbable path from the start to the end.
+
+use aoc_core::random::Rng;
+
+/// The fewest squares a path needs to climb from `a` to `z`.
+const CLIMB: usize = 26;
+
+/// A heightmap about `size` squares wide (but at least big enough to climb from `a` to `z`),
+/// with a winding path up to the end hidden among random elevations.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let width = 3 + rng.below(size.clamp(1, 8));
+    let height = CLIMB.div_ceil(width) + rng.below(3);
+    let mut elevations = (0..width * height)
+        .map(|_| b'a' + rng.below(26) as u8)
+        .collect::<Vec<_>>();
+
+    // Snake back and forth across the rows, starting from a random corner.
+    let (flip_x, flip_y) = (rng.chance(0.5), rng.chance(0.5));
+    let mut path = Vec::with_capacity(width * height);
+    for row in 0..height {
+        let y = if flip_y { height - 1 - row } else { row };
+        let forwards = (row % 2 == 0) != flip_x;
+        for column in 0..width {
+            let x = if forwards { column } else { width - 1 - column };
+            path.push(y * width + x);
+        }
+    }
+    path.truncate(CLIMB + rng.below(path.len() - CLIMB + 1));
+
+    // Climb one step at a time on 25 of the moves along the path, and stay level on the rest.
+    let mut climbs = vec![true; CLIMB - 1];
+    climbs.resize(path.len() - 1, false);
+    rng.shuffle(&mut climbs);
+    let mut elevation = b'a';
+    elevations[path[0]] = b'S';
+    for (&square, climb) in path[1..].iter().zip(climbs) {
+        elevation += climb as u8;
+        elevations[square] = elevation;
+    }
+    elevations[path[path.len() - 1]] = b'E';
+
+    let mut input = String::new();
+    for row in elevations.chunks(width) {
+        input.push_str(std::str::from_utf8(row).expect("elevations are ASCII letters"));
+        input.push('\n');
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day12;
+    use aoc_core::Day;
+
+    #[test]
+    fn test_has_path() {
+        for seed in 0..10 {
+            let input = input(&mut Rng::new(seed), 5);
+            let heightmap = Day12.parse(&input).unwrap();
+            let unreachable = heightmap.width() * heightmap.height() + 2;
+            assert!(
+                Day12.part_1(&heightmap) < unreachable,
+                "seed {seed}:\n{input}"
+            );
+        }
+    }
+}
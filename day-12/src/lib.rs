@@ -0,0 +1,249 @@
+//! Day 12: Hill Climbing Algorithm.
+
+pub mod generate;
+pub mod vis;
+
+use aoc_core::{random::Rng, Day, ParseError};
+use geometry::{Direction, Point};
+use grid::Grid;
+use std::collections::HashMap;
+
+/// Hill Climbing Algorithm: find the fewest steps up the hill to the best signal.
+pub struct Day12;
+
+impl Day for Day12 {
+    const DAY: u8 = 12;
+    type Input<'a> = Heightmap;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        Heightmap::parse(input)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>) -> Self::Part1 {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>) -> Self::Part2 {
+        part_2(input)
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+}
+
+/// The fewest steps from the start to the location with the best signal.
+pub fn part_1(heightmap: &Heightmap) -> usize {
+    let pathfinder = Pathfinder::new(heightmap);
+    let mut smallest = heightmap.width() * heightmap.height() + 2;
+    for path in pathfinder {
+        let steps = path.len() - 1;
+        if steps < smallest {
+            smallest = steps
+        }
+    }
+    smallest
+}
+
+/// The fewest steps from any square at elevation `a` to the location with the best signal.
+pub fn part_2(heightmap: &Heightmap) -> usize {
+    let mut smallest = heightmap.width() * heightmap.height() + 2;
+    for low in heightmap.lows() {
+        let pathfinder = Pathfinder::new_with_start(heightmap, low);
+        for path in pathfinder {
+            let steps = path.len() - 1;
+            if steps < smallest {
+                smallest = steps
+            }
+        }
+    }
+    smallest
+}
+
+/// The heightmap, with the start and end marked.
+pub struct Heightmap {
+    start: Point,
+    end: Point,
+    elevations: Grid<u8>,
+}
+
+impl Heightmap {
+    /// The elevation at `point`, from `b'a'` to `b'z'`.
+    pub fn at(&self, point: Point) -> u8 {
+        self.elevations[point]
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        self.elevations.contains(point)
+    }
+
+    pub fn width(&self) -> usize {
+        self.elevations.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.elevations.height()
+    }
+
+    /// Whether you can step from `from` to `to`: at most one higher, or any amount lower.
+    pub fn move_allowed(&self, from: Point, to: Point) -> bool {
+        if !self.contains(from) || !self.contains(to) {
+            return false;
+        }
+
+        if from.manhattan_distance(to) != 1 {
+            return false;
+        }
+
+        let from = self.at(from);
+        let to = self.at(to);
+        from > to || to - from <= 1
+    }
+
+    /// Every point at the lowest elevation.
+    pub fn lows(&self) -> Vec<Point> {
+        self.elevations
+            .iter()
+            .filter(|(_, elevation)| **elevation == b'a')
+            .map(|(point, _)| point)
+            .collect()
+    }
+
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut start = None;
+        let mut end = None;
+        let elevations = Grid::parse_from_chars(input, |point, c| match c {
+            'S' => {
+                start = Some(point);
+                Ok(b'a')
+            }
+            'E' => {
+                end = Some(point);
+                Ok(b'z')
+            }
+            'a'..='z' => Ok(c as u8),
+            _ => Err("expected `S`, `E`, or an elevation from `a` to `z`"),
+        })?;
+
+        let start = start.ok_or("expected a start marked `S`")?;
+        let end = end.ok_or("expected an end marked `E`")?;
+        Ok(Self {
+            start,
+            end,
+            elevations,
+        })
+    }
+}
+
+/// Yields every path from the start to the end that is shorter than the paths found before it.
+/// The last path yielded is one of the shortest.
+pub struct Pathfinder<'a> {
+    heightmap: &'a Heightmap,
+    search: Search,
+}
+
+impl<'a> Pathfinder<'a> {
+    pub fn new(heightmap: &'a Heightmap) -> Self {
+        Self::new_with_start(heightmap, heightmap.start)
+    }
+
+    pub fn new_with_start(heightmap: &'a Heightmap, start: Point) -> Self {
+        Self {
+            heightmap,
+            search: Search::new(start),
+        }
+    }
+}
+
+impl<'a> Iterator for Pathfinder<'a> {
+    type Item = Vec<Point>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.search.step(self.heightmap) {
+                Step::Moved => continue,
+                Step::Found(path) => return Some(path),
+                Step::Done => return None,
+            }
+        }
+    }
+}
+
+/// A depth-first search for paths to the end, which skips any square it has already reached by a
+/// path at least as short.
+pub struct Search {
+    shortest_path_so_far: HashMap<Point, usize>,
+    stack: Vec<(Point, std::array::IntoIter<Direction, 4>)>,
+}
+
+/// What a single [`Search::step`] did.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Step {
+    /// Went one square further, or backed up one square with nowhere left to try.
+    Moved,
+    /// Reached the end by a path shorter than any found before.
+    Found(Vec<Point>),
+    /// Every path has been tried.
+    Done,
+}
+
+impl Search {
+    pub fn new(start: Point) -> Self {
+        Self {
+            shortest_path_so_far: HashMap::from([(start, 1)]),
+            stack: vec![(start, Direction::CARDINAL.into_iter())],
+        }
+    }
+
+    /// The squares on the path being explored, from the start.
+    pub fn path(&self) -> impl Iterator<Item = Point> + '_ {
+        self.stack.iter().map(|(pt, _)| *pt)
+    }
+
+    pub fn step(&mut self, heightmap: &Heightmap) -> Step {
+        loop {
+            let current_stack_length = self.stack.len();
+            let Some(last_mut) = self.stack.last_mut() else {
+                return Step::Done;
+            };
+            let next_direction = last_mut.1.next();
+            let Some(next_direction) = next_direction else {
+                self.stack.pop();
+                return Step::Moved;
+            };
+            let next_point = last_mut.0.step(next_direction);
+            let shortest_path_so_far = self.shortest_path_so_far.get(&next_point).copied();
+            if shortest_path_so_far.is_some()
+                && shortest_path_so_far.unwrap() <= current_stack_length + 1
+            {
+                continue;
+            }
+            if !heightmap.move_allowed(last_mut.0, next_point) {
+                continue;
+            }
+            if next_point == heightmap.end {
+                let mut path = self.path().collect::<Vec<_>>();
+                path.push(next_point);
+                return Step::Found(path);
+            }
+
+            self.shortest_path_so_far
+                .insert(next_point, current_stack_length + 1);
+            self.stack
+                .push((next_point, Direction::CARDINAL.into_iter()));
+            return Step::Moved;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_core::example_tests! {
+        test_part_1: Day12, "example.txt", part_1 => 31;
+        test_part_2: Day12, "example.txt", part_2 => 29;
+    }
+}
@@ -0,0 +1,109 @@
+//! An animation of the search for the shortest path up the hill, showing the path being explored
+//! and the shortest path found so far.
+
+use super::{Heightmap, Search, Step};
+use geometry::{Bounds, Point};
+use vis::{Canvas, Viewport, Visualize};
+
+pub struct Visualization {
+    heightmap: Heightmap,
+    search: Search,
+    shortest: Option<Vec<Point>>,
+    done: bool,
+}
+
+impl Visualization {
+    pub fn new(heightmap: Heightmap) -> Self {
+        let search = Search::new(heightmap.start);
+        Self {
+            heightmap,
+            search,
+            shortest: None,
+            done: false,
+        }
+    }
+
+    /// The shortest path from the start to the end found so far.
+    pub fn shortest(&self) -> Option<&[Point]> {
+        self.shortest.as_deref()
+    }
+
+    fn step(&mut self) -> Step {
+        let step = self.search.step(&self.heightmap);
+        match &step {
+            Step::Moved => {}
+            Step::Found(path) => self.shortest = Some(path.clone()),
+            Step::Done => self.done = true,
+        }
+        step
+    }
+}
+
+impl Visualize for Visualization {
+    /// Every step moves the end of the path being explored by one square; otherwise the search runs
+    /// until it finds a shorter path.
+    fn tick(&mut self, every_step: bool) -> bool {
+        let mut step = self.step();
+        while !every_step && step == Step::Moved {
+            step = self.step();
+        }
+        self.done
+    }
+
+    fn draw(&self, canvas: &Canvas) {
+        canvas.clear();
+        let world = Bounds {
+            min: Point::ORIGIN,
+            max: Point::new(
+                self.heightmap.width() as i32 - 1,
+                self.heightmap.height() as i32 - 1,
+            ),
+        };
+        let focus = self.search.path().last().unwrap_or(self.heightmap.end);
+        let viewport = Viewport::within(focus, world);
+
+        // Higher squares are lighter.
+        for (point, &elevation) in self.heightmap.elevations.iter() {
+            if let Some((x, y)) = viewport.to_screen(point) {
+                let shade = 20 + (elevation - b'a') * 6;
+                canvas.set_fill_color_rgb(shade, shade, shade);
+                canvas.fill_box(x, y);
+            }
+        }
+
+        canvas.dark_green();
+        for &point in self.shortest.iter().flatten() {
+            if let Some((x, y)) = viewport.to_screen(point) {
+                canvas.fill_box(x, y);
+            }
+        }
+        canvas.dark_red();
+        for point in self.search.path() {
+            if let Some((x, y)) = viewport.to_screen(point) {
+                canvas.fill_box(x, y);
+            }
+        }
+
+        canvas.bright_green();
+        for (point, byte) in [(self.heightmap.start, b'S'), (self.heightmap.end, b'E')] {
+            if let Some((x, y)) = viewport.to_screen(point) {
+                canvas.draw_byte(x, y, byte);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_finds_the_shortest_path() {
+        let heightmap = Heightmap::parse(include_str!("example.txt")).unwrap();
+        let mut vis = Visualization::new(heightmap);
+        while !vis.tick(false) {
+            assert!(vis.shortest().is_some());
+        }
+        assert_eq!(vis.shortest().map(|path| path.len() - 1), Some(31));
+    }
+}
@@ -0,0 +1,47 @@
+//! Random pairs of packets. Integers are usually small like in the real input, but now and then
+//! one is as big as a byte allows.
+
+use aoc_core::random::Rng;
+
+/// `size` pairs of packets.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let pairs = (0..size.max(1))
+        .map(|_| format!("{}\n{}\n", list(rng, 0), list(rng, 0)))
+        .collect::<Vec<_>>();
+    pairs.join("\n")
+}
+
+fn list(rng: &mut Rng, depth: usize) -> String {
+    let elements = (0..rng.below(6))
+        .map(|_| {
+            if depth < 4 && rng.chance(0.3) {
+                list(rng, depth + 1)
+            } else if rng.chance(0.002) {
+                rng.range(0..=255).to_string()
+            } else {
+                rng.range(0..=10).to_string()
+            }
+        })
+        .collect::<Vec<_>>();
+    format!("[{}]", elements.join(","))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pairs() {
+        let input = input(&mut Rng::new(13), 10);
+        let pairs = input.split("\n\n").collect::<Vec<_>>();
+        assert_eq!(pairs.len(), 10);
+        for pair in pairs {
+            let packets = pair.lines().collect::<Vec<_>>();
+            assert_eq!(packets.len(), 2);
+            for packet in packets {
+                assert!(packet.starts_with('[') && packet.ends_with(']'));
+                assert_eq!(packet.matches('[').count(), packet.matches(']').count());
+            }
+        }
+    }
+}
@@ -0,0 +1,278 @@
+//! Day 13: Distress Signal.
+
+pub mod generate;
+
+use aoc_core::{random::Rng, Day, ParseError};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::digit1,
+    combinator::{cut, map, map_res},
+    error::ErrorKind,
+    multi::separated_list1,
+    sequence::{preceded, terminated},
+    IResult,
+};
+use std::{fmt::Debug, str::FromStr};
+
+/// Distress Signal: put the packets from the distress signal in the right order.
+pub struct Day13;
+
+impl Day for Day13 {
+    const DAY: u8 = 13;
+    type Input<'a> = Vec<(Packet, Packet)>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>) -> Self::Part1 {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>) -> Self::Part2 {
+        part_2(input)
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+}
+
+/// The sum of the (one-based) indices of the pairs that are already in the right order.
+pub fn part_1(input: &[(Packet, Packet)]) -> usize {
+    let mut sum = 0;
+    for (idx, pair) in input
+        .iter()
+        .enumerate()
+        .map(|(idx, other)| (idx + 1, other))
+    {
+        let left = &pair.0;
+        let right = &pair.1;
+        if left.cmp(right) == std::cmp::Ordering::Less {
+            sum += idx;
+        }
+    }
+    sum
+}
+
+/// The decoder key: the product of the positions of the `[[2]]` and `[[6]]` divider packets once
+/// every packet is sorted.
+pub fn part_2(input: &[(Packet, Packet)]) -> usize {
+    let mut vec = Vec::with_capacity(input.len() * 2);
+    for item in input {
+        vec.push(&item.0);
+        vec.push(&item.1);
+    }
+    let marker1 = "[[2]]".parse().unwrap();
+    let marker2 = "[[6]]".parse().unwrap();
+    vec.push(&marker1);
+    vec.push(&marker2);
+    vec.sort();
+
+    vec.into_iter()
+        .enumerate()
+        .map(|(idx, val)| (idx + 1, val))
+        .filter(|(_, val)| *val == &marker1 || *val == &marker2)
+        .map(|(idx, _)| idx)
+        .product()
+}
+
+/// Parse pairs of packets, one per line, with a blank line after each pair.
+pub fn parse(input: &str) -> Result<Vec<(Packet, Packet)>, ParseError> {
+    // Probably coulda used nom here, too, but meh.
+    let lines = input.lines().collect::<Vec<_>>();
+    let mut vec = Vec::new();
+    for chunk in lines.chunks(3) {
+        if chunk.len() < 2 {
+            return Err(ParseError::new("expected a second packet to pair with").within(chunk[0]));
+        }
+        let packet1 = chunk[0]
+            .parse::<Packet>()
+            .map_err(|err| err.within(chunk[0]))?;
+        let packet2 = chunk[1]
+            .parse::<Packet>()
+            .map_err(|err| err.within(chunk[1]))?;
+        if chunk.len() == 3 && !chunk[2].is_empty() {
+            return Err(ParseError::expected("a blank line between pairs", chunk[2]));
+        }
+        vec.push((packet1, packet2))
+    }
+    Ok(vec)
+}
+
+/// Either an integer or a list of packets. Packets are ordered the way the puzzle compares them.
+#[derive(Clone, Eq, PartialEq)]
+pub enum Packet {
+    Int(u8),
+    List(Vec<Packet>),
+}
+
+impl Debug for Packet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Int(i) => write!(f, "{i}"),
+            Self::List(list) => {
+                f.write_str("[")?;
+                let mut is_first = true;
+                for packet in list {
+                    if is_first {
+                        is_first = false;
+                    } else {
+                        f.write_str(",")?;
+                    }
+                    packet.fmt(f)?;
+                }
+                f.write_str("]")?;
+                Ok(())
+            }
+        }
+    }
+}
+
+impl FromStr for Packet {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        fn packet(input: &str) -> IResult<&str, Packet> {
+            let list = map(list, Packet::List);
+            let int = map(int, Packet::Int);
+            let mut s = alt((list, int));
+            s(input)
+        }
+
+        // Once a list has started, `cut` stops nom from backtracking out of it, so that errors
+        // point at the element that's wrong rather than at the opening `[`.
+        fn list(input: &str) -> IResult<&str, Vec<Packet>> {
+            let empty = map(tag("]"), |_| Vec::new());
+            let elements = terminated(separated_list1(tag(","), cut(packet)), tag("]"));
+            preceded(tag("["), cut(alt((empty, elements))))(input)
+        }
+
+        fn int(input: &str) -> IResult<&str, u8> {
+            map_res(digit1, str::parse)(input)
+        }
+
+        let (rest, packet) = packet(s).map_err(|err| match err {
+            nom::Err::Error(err) | nom::Err::Failure(err) if err.code == ErrorKind::Tag => {
+                ParseError::expected_at("`,` or `]`", err.input)
+            }
+            nom::Err::Error(err) | nom::Err::Failure(err) if err.code == ErrorKind::MapRes => {
+                let end = err.input.find(|c: char| !c.is_ascii_digit());
+                let digits = &err.input[..end.unwrap_or(err.input.len())];
+                ParseError::expected("an integer from 0 to 255", digits)
+            }
+            nom::Err::Error(err) | nom::Err::Failure(err) => {
+                ParseError::expected_at("a list or an integer", err.input)
+            }
+            nom::Err::Incomplete(_) => ParseError::expected_at("a list or an integer", ""),
+        })?;
+        if rest.is_empty() {
+            Ok(packet)
+        } else {
+            Err(ParseError::expected_at("the end of the packet", rest))
+        }
+    }
+}
+
+impl Ord for Packet {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        match (self, other) {
+            (Packet::Int(a), Packet::Int(b)) => a.cmp(b),
+            (Packet::Int(a), b @ Packet::List(_)) => {
+                let wrapped = Packet::List(vec![Packet::Int(*a)]);
+                wrapped.cmp(b)
+            }
+            (a @ Packet::List(_), Packet::Int(b)) => {
+                let wrapped = Packet::List(vec![Packet::Int(*b)]);
+                a.cmp(&wrapped)
+            }
+            (Packet::List(a), Packet::List(b)) => {
+                let a_len = a.len();
+                let b_len = b.len();
+                for idx in 0..std::cmp::min(a_len, b_len) {
+                    let a_item = a.get(idx).unwrap();
+                    let b_item = b.get(idx).unwrap();
+                    let cmp = a_item.cmp(b_item);
+                    if !cmp.is_eq() {
+                        return cmp;
+                    }
+                }
+                a_len.cmp(&b_len)
+            }
+        }
+    }
+}
+
+impl PartialOrd for Packet {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    macro_rules! packet_cmp {
+        ($first:literal, $second:literal) => {{
+            let first = $first
+                .parse::<Packet>()
+                .expect("expected first to be parsed");
+            let second = $second
+                .parse::<Packet>()
+                .expect("expected second to be parsed");
+            first.cmp(&second)
+        }};
+    }
+    macro_rules! assert_lt {
+        ($first:literal, $second:literal) => {
+            assert_eq!(packet_cmp!($first, $second), std::cmp::Ordering::Less);
+        };
+    }
+    macro_rules! assert_gt {
+        ($first:literal, $second:literal) => {
+            assert_eq!(packet_cmp!($first, $second), std::cmp::Ordering::Greater);
+        };
+    }
+
+    #[test]
+    fn test_part_1_examples() {
+        assert_lt!("[1,1,3,1,1]", "[1,1,5,1,1]");
+        assert_lt!("[[1],[2,3,4]]", "[[1],4]");
+        assert_gt!("[9]", "[[8,7,6]]");
+        assert_lt!("[[4,4],4,4]", "[[4,4],4,4,4]");
+        assert_gt!("[7,7,7,7]", "[7,7,7]");
+        assert_lt!("[]", "[3]");
+        assert_gt!("[[[]]]", "[[]]");
+        assert_gt!("[1,[2,[3,[4,[5,6,7]]]],8,9]", "[1,[2,[3,[4,[5,6,0]]]],8,9]");
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = "[1,2]\n[3]\n\n[1,[2]]\n[1,x]\n";
+        let err = parse(input).unwrap_err().locate(input);
+        let location = err.location().unwrap();
+        assert_eq!((location.line(), location.column()), (5, 4));
+        assert_eq!(err.message(), "expected a list or an integer, found `x`");
+
+        let err = "[1,[2]".parse::<Packet>().unwrap_err();
+        assert_eq!(err.message(), "expected `,` or `]`, found nothing");
+
+        let input = "[256]\n[1]\n";
+        let err = parse(input).unwrap_err().locate(input);
+        let location = err.location().unwrap();
+        assert_eq!((location.line(), location.column()), (1, 2));
+        assert_eq!(
+            err.message(),
+            "expected an integer from 0 to 255, found `256`"
+        );
+    }
+
+    aoc_core::example_tests! {
+        test_part_1: Day13, "example.txt", part_1 => 13;
+        test_part_2: Day13, "example.txt", part_2 => 140;
+    }
+}
@@ -0,0 +1,47 @@
+//! Random scans of rock paths around the point the sand pours in from.
+
+use aoc_core::random::Rng;
+
+/// Up to `size` paths of rock, each made of horizontal and vertical lines.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1) as i64;
+    let mut input = String::new();
+    for _ in 0..rng.range(1..=size) {
+        let mut x = rng.range(500 - size..=500 + size);
+        let mut y = rng.range(1..=2 * size);
+        let mut points = vec![format!("{x},{y}")];
+        let mut horizontal = rng.chance(0.5);
+        for _ in 0..rng.below(5) {
+            let length = rng.range(1..=(size + 1) / 2);
+            let mut length = if rng.chance(0.5) { length } else { -length };
+            if horizontal {
+                x += length;
+            } else {
+                // Rock never reaches up to where the sand pours in from.
+                if y + length < 1 {
+                    length = -length;
+                }
+                y += length;
+            }
+            horizontal = !horizontal;
+            points.push(format!("{x},{y}"));
+        }
+        input.push_str(&points.join(" -> "));
+        input.push('\n');
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_input;
+
+    #[test]
+    fn test_parses() {
+        for seed in 0..20 {
+            let input = input(&mut Rng::new(seed), 10);
+            assert!(parse_input(&input).is_ok(), "seed {seed}:\n{input}");
+        }
+    }
+}
@@ -0,0 +1,310 @@
+//! Day 14: Regolith Reservoir.
+
+pub mod generate;
+pub mod vis;
+
+use aoc_core::{parse_lines, random::Rng, Day, ParseError};
+use geometry::{Bounds, Direction, Point, Vector};
+use grid::Grid;
+use std::str::FromStr;
+
+use nom::bytes::complete::tag;
+use nom::character::complete::i32;
+use nom::multi::separated_list1;
+use nom::sequence::preceded;
+use nom::{sequence::tuple, IResult};
+
+/// Regolith Reservoir: pour sand into a cave until it comes to rest.
+pub struct Day14;
+
+impl Day for Day14 {
+    const DAY: u8 = 14;
+    type Input<'a> = Vec<RockPath>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>) -> Self::Part1 {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>) -> Self::Part2 {
+        part_2(input)
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+}
+
+/// Parse one path of rock per line.
+pub fn parse_input(input: &str) -> Result<Vec<RockPath>, ParseError> {
+    parse_lines(input.trim(), str::parse)
+}
+
+/// How many units of sand come to rest before sand starts falling into the abyss, or before the
+/// source is blocked if the rock catches all of it.
+pub fn part_1(paths: &[RockPath]) -> usize {
+    let mut cave = Cave::new(paths);
+
+    let mut resting = 0;
+    loop {
+        let mut sand = Point { x: 500, y: 0 };
+        'sanddrop: loop {
+            if sand.y > cave.max_rock() {
+                return resting;
+            }
+            for possible_drop in FALLS.map(|direction| sand.step(direction)) {
+                if cave.get_material(possible_drop).is_none() {
+                    sand = possible_drop;
+                    continue 'sanddrop;
+                }
+            }
+            // The sand has come to rest!
+            cave.set_material(sand, Material::Sand);
+            resting += 1;
+            if sand.y == 0 {
+                return resting;
+            }
+            break;
+        }
+    }
+}
+
+/// How many units of sand come to rest, on top of the floor, before the source is blocked.
+pub fn part_2(paths: &[RockPath]) -> usize {
+    let mut cave = Cave::new(paths);
+
+    let mut resting = 0;
+    'outer: loop {
+        let mut sand = Point { x: 500, y: 0 };
+        'sanddrop: loop {
+            if sand.y > cave.max_rock() {
+                cave.set_material(sand, Material::Sand);
+                resting += 1;
+                if sand.y == 0 {
+                    break 'outer;
+                }
+                break;
+            }
+            for possible_drop in FALLS.map(|direction| sand.step(direction)) {
+                if cave.get_material(possible_drop).is_none() {
+                    sand = possible_drop;
+                    continue 'sanddrop;
+                }
+            }
+            // The sand has come to rest!
+            cave.set_material(sand, Material::Sand);
+            resting += 1;
+            if sand.y == 0 {
+                break 'outer;
+            }
+            break;
+        }
+    }
+
+    resting
+}
+
+/// Where a unit of sand tries to fall, in order: straight down, then down and to the left, then
+/// down and to the right.
+const FALLS: [Direction; 3] = [Direction::South, Direction::SouthWest, Direction::SouthEast];
+
+/// A path of rock, written as waypoints joined by straight lines like `498,4 -> 498,6 -> 496,6`.
+#[derive(Clone)]
+pub struct RockPath {
+    waypoints: Vec<Point>,
+}
+
+impl IntoIterator for RockPath {
+    type Item = Point;
+    type IntoIter = RockPathIterator;
+
+    fn into_iter(self) -> Self::IntoIter {
+        RockPathIterator::new(self)
+    }
+}
+
+/// Yields every point of rock along a [`RockPath`].
+pub struct RockPathIterator {
+    current_point: Option<Point>,
+    target_point: Option<Point>,
+    waypoints: Vec<Point>,
+}
+
+impl RockPathIterator {
+    pub fn new(path: RockPath) -> Self {
+        let mut waypoints = path.waypoints;
+        waypoints.reverse();
+        let current_point = waypoints.pop();
+        let target_point = waypoints.pop();
+        Self {
+            current_point,
+            target_point,
+            waypoints,
+        }
+    }
+}
+
+impl Iterator for RockPathIterator {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let point = self.current_point?;
+        let Some(target_point) = self.target_point else {
+            self.current_point = None;
+            return Some(point);
+        };
+        if point == target_point {
+            panic!("Unexpected");
+        }
+
+        let next_point = point + (target_point - point).signum();
+        if next_point == target_point {
+            self.target_point = self.waypoints.pop();
+        }
+
+        self.current_point = Some(next_point);
+
+        Some(point)
+    }
+}
+
+fn parse_rockpath(i: &str) -> IResult<&str, RockPath> {
+    let (i, waypoints) = separated_list1(tag(" -> "), parse_point)(i)?;
+    let rockpath = RockPath { waypoints };
+    Ok((i, rockpath))
+}
+
+fn parse_point(i: &str) -> IResult<&str, Point> {
+    let (i, (x, y)) = tuple((i32, preceded(tag(","), i32)))(i)?;
+    Ok((i, Point { x, y }))
+}
+
+impl FromStr for RockPath {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match parse_rockpath(s) {
+            Ok(("", rockpath)) => Ok(rockpath),
+            Ok((rest, _)) => Err(ParseError::expected_at("` -> ` and another point", rest)),
+            Err(nom::Err::Error(err) | nom::Err::Failure(err)) => {
+                Err(ParseError::expected_at("a point like `498,4`", err.input))
+            }
+            Err(nom::Err::Incomplete(_)) => Err(ParseError::expected_at("a point", "")),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+enum Material {
+    Rock,
+    Sand,
+}
+
+/// Where sand pours in from.
+const SOURCE: Point = Point::new(500, 0);
+
+/// The part of the cave that sand can reach.
+struct Cave {
+    materials: Grid<Option<Material>>,
+    /// How far the top left corner of `materials` is from `(0, 0)` in the cave.
+    offset: Vector,
+    max_rock: i32,
+}
+
+impl Cave {
+    fn new(paths: &[RockPath]) -> Self {
+        let rocks = paths.iter().cloned().flatten().collect::<Vec<_>>();
+        let mut bounds = Bounds::from_point(SOURCE);
+        for rock in &rocks {
+            bounds.include(*rock);
+        }
+        let max_rock = bounds.max.y;
+
+        // Sand comes to rest no lower than just above the floor, and so spreads at most that far to
+        // either side of the source.
+        let floor = max_rock + 2;
+        bounds.include(SOURCE + Vector::new(-floor, floor));
+        bounds.include(SOURCE + Vector::new(floor, floor));
+
+        let offset = bounds.min - Point::ORIGIN;
+        let materials = Grid::new(bounds.width() as usize, bounds.height() as usize, None);
+        let mut cave = Cave {
+            materials,
+            offset,
+            max_rock,
+        };
+        for rock in rocks {
+            cave.set_material(rock, Material::Rock);
+        }
+        cave
+    }
+
+    pub fn set_material(&mut self, point: Point, material: Material) {
+        let cell = self
+            .materials
+            .get_mut(point - self.offset)
+            .expect("sand can't leave the cave");
+        *cell = Some(material);
+    }
+
+    pub fn get_material(&self, point: Point) -> Option<Material> {
+        self.materials.get(point - self.offset).copied().flatten()
+    }
+
+    pub fn max_rock(&self) -> i32 {
+        self.max_rock
+    }
+
+    /// Every point in the cave that sand could reach.
+    pub fn bounds(&self) -> Bounds {
+        let min = Point::ORIGIN + self.offset;
+        Bounds {
+            min,
+            max: Point::new(
+                min.x + self.materials.width() as i32 - 1,
+                min.y + self.materials.height() as i32 - 1,
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rockpath() {
+        macro_rules! pt {
+            ($x:expr, $y:expr) => {
+                Point { x: $x, y: $y }
+            };
+        }
+
+        let input = "498,4 -> 498,6 -> 496,6";
+        let rockpath = input.parse::<RockPath>().unwrap();
+        let mut iter = rockpath.into_iter();
+        assert_eq!(iter.next(), Some(pt!(498, 4)));
+        assert_eq!(iter.next(), Some(pt!(498, 5)));
+        assert_eq!(iter.next(), Some(pt!(498, 6)));
+        assert_eq!(iter.next(), Some(pt!(497, 6)));
+        assert_eq!(iter.next(), Some(pt!(496, 6)));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_part_1_blocked() {
+        // A bowl under the source that catches all of the sand.
+        let paths = parse_input("497,2 -> 497,4 -> 503,4 -> 503,2").unwrap();
+        assert_eq!(part_1(&paths), 14);
+    }
+
+    aoc_core::example_tests! {
+        test_part_1: Day14, "example.txt", part_1 => 24;
+        test_part_2: Day14, "example.txt", part_2 => 93;
+    }
+}
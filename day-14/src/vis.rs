@@ -0,0 +1,113 @@
+//! An animation of part 1: sand pours in a grain at a time until it starts falling into the abyss.
+
+use super::{Cave, Material, RockPath, FALLS, SOURCE};
+use geometry::Point;
+use vis::{Canvas, Viewport, Visualize};
+
+pub struct Visualization {
+    cave: Cave,
+    /// The grain of sand on its way down, if there is one.
+    falling: Option<Point>,
+    resting: usize,
+    done: bool,
+}
+
+impl Visualization {
+    pub fn new(paths: &[RockPath]) -> Self {
+        Self {
+            cave: Cave::new(paths),
+            falling: None,
+            resting: 0,
+            done: false,
+        }
+    }
+
+    /// How many grains of sand have come to rest so far.
+    pub fn resting(&self) -> usize {
+        self.resting
+    }
+
+    /// Drop a new grain from the source, or move the falling one down by one.
+    fn step(&mut self) {
+        if self.done {
+            return;
+        }
+        let Some(sand) = self.falling else {
+            self.falling = Some(SOURCE);
+            return;
+        };
+        if sand.y > self.cave.max_rock() {
+            self.done = true;
+            return;
+        }
+        for possible_drop in FALLS.map(|direction| sand.step(direction)) {
+            if self.cave.get_material(possible_drop).is_none() {
+                self.falling = Some(possible_drop);
+                return;
+            }
+        }
+        self.cave.set_material(sand, Material::Sand);
+        self.resting += 1;
+        self.falling = None;
+        self.done = sand.y == SOURCE.y;
+    }
+}
+
+impl Visualize for Visualization {
+    /// Every step moves the falling grain by one; otherwise a whole grain falls until it rests.
+    fn tick(&mut self, every_step: bool) -> bool {
+        self.step();
+        while !every_step && !self.done && self.falling.is_some() {
+            self.step();
+        }
+        self.done
+    }
+
+    fn draw(&self, canvas: &Canvas) {
+        canvas.clear();
+        let bounds = self.cave.bounds();
+        let viewport = Viewport::within(self.falling.unwrap_or(SOURCE), bounds);
+
+        for point in bounds.points() {
+            let Some((x, y)) = viewport.to_screen(point) else {
+                continue;
+            };
+            match self.cave.get_material(point) {
+                Some(Material::Rock) => canvas.gray(),
+                Some(Material::Sand) => canvas.set_fill_color_rgb(150, 130, 80),
+                None => continue,
+            }
+            canvas.fill_box(x, y);
+        }
+
+        canvas.set_fill_color_rgb(255, 220, 120);
+        if let Some((x, y)) = self.falling.and_then(|sand| viewport.to_screen(sand)) {
+            canvas.fill_box(x, y);
+        }
+        if let Some((x, y)) = viewport.to_screen(SOURCE) {
+            canvas.draw_byte(x, y, b'+');
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_input;
+
+    #[test]
+    fn test_rests_as_many_as_part_1() {
+        let paths = parse_input(include_str!("example.txt")).unwrap();
+        let mut vis = Visualization::new(&paths);
+        let mut grains = 0;
+        while !vis.tick(false) {
+            grains += 1;
+        }
+        assert_eq!(vis.resting(), 24);
+        assert_eq!(grains, 24);
+
+        let mut vis = Visualization::new(&paths);
+        while !vis.tick(true) {}
+        assert_eq!(vis.resting(), 24);
+    }
+}
@@ -0,0 +1,66 @@
+//! Random sensor reports that, like the real ones, leave exactly one position in the search area
+//! where the distress beacon could be.
+
+use aoc_core::random::Rng;
+
+/// Reports for `size` sensors, plus four more that fence in a hidden beacon somewhere in the
+/// square from `0` to `square`.
+pub fn input(rng: &mut Rng, size: usize, square: i32) -> String {
+    let square = i64::from(square.max(1));
+    let hidden = (rng.range(0..=square), rng.range(0..=square));
+
+    let mut pairs = Vec::new();
+    // A sensor diagonally out from the hidden beacon, that only just misses it, covers the whole
+    // quadrant of the square on its side. Four of them cover everything else.
+    for (dx, dy) in [(1, 1), (1, -1), (-1, 1), (-1, -1)] {
+        let distance = rng.range(square + 1..=2 * square + 1);
+        let sensor = (hidden.0 + dx * distance, hidden.1 + dy * distance);
+        pairs.push((sensor, beacon(rng, sensor, 2 * distance - 1)));
+    }
+    while pairs.len() < size + 4 {
+        let sensor = (
+            rng.range(-square..=2 * square),
+            rng.range(-square..=2 * square),
+        );
+        let to_hidden = (sensor.0 - hidden.0).abs() + (sensor.1 - hidden.1).abs();
+        if to_hidden < 2 {
+            continue;
+        }
+        let distance = rng.range(1..=to_hidden - 1);
+        pairs.push((sensor, beacon(rng, sensor, distance)));
+    }
+    rng.shuffle(&mut pairs);
+
+    let mut input = String::new();
+    for ((sx, sy), (bx, by)) in pairs {
+        input.push_str(&format!(
+            "Sensor at x={sx}, y={sy}: closest beacon is at x={bx}, y={by}\n"
+        ));
+    }
+    input
+}
+
+/// A beacon exactly `distance` away from `sensor`.
+fn beacon(rng: &mut Rng, sensor: (i64, i64), distance: i64) -> (i64, i64) {
+    let dx = rng.range(-distance..=distance);
+    let dy = distance - dx.abs();
+    let dy = if rng.chance(0.5) { dy } else { -dy };
+    (sensor.0 + dx, sensor.1 + dy)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse_input, part_2};
+
+    #[test]
+    fn test_hidden_beacon() {
+        for seed in 0..10 {
+            let input = input(&mut Rng::new(seed), 10, 20);
+            let pairs = parse_input(&input).unwrap();
+            let frequency = part_2(&pairs, 20);
+            let (x, y) = (frequency / 4_000_000, frequency % 4_000_000);
+            assert!((0..=20).contains(&x) && (0..=20).contains(&y));
+        }
+    }
+}
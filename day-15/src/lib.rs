@@ -0,0 +1,275 @@
+//! Day 15: Beacon Exclusion Zone.
+
+pub mod generate;
+
+use aoc_core::{parse_lines, random::Rng, Day, ParseError};
+use geometry::{Direction, Point};
+use interval::{Interval, IntervalSet};
+use std::{collections::HashSet, str::FromStr};
+
+/// The row part one asks about in the real puzzle input. The example uses row 10 instead.
+pub const PART_1_ROW: i32 = 2_000_000;
+
+/// The size of the search area for part two in the real puzzle input. The example uses 20 instead.
+pub const PART_2_SIZE: i32 = 4_000_000;
+
+/// Day 15's answers depend on more than the input: part one asks about a single row and part two
+/// searches a square area, and both are smaller in the example than in the real puzzle.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Day15 {
+    /// The row part one counts positions on.
+    pub row: i32,
+    /// The largest `x` and `y` part two searches.
+    pub size: i32,
+}
+
+impl Day15 {
+    /// The row and search area the example uses.
+    pub const EXAMPLE: Day15 = Day15 { row: 10, size: 20 };
+}
+
+impl Default for Day15 {
+    fn default() -> Self {
+        Self {
+            row: PART_1_ROW,
+            size: PART_2_SIZE,
+        }
+    }
+}
+
+impl Day for Day15 {
+    const DAY: u8 = 15;
+    type Input<'a> = Vec<Pair>;
+    type Part1 = usize;
+    type Part2 = i64;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>) -> Self::Part1 {
+        part_1(input, self.row)
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>) -> Self::Part2 {
+        part_2(input, self.size)
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size, self.size))
+    }
+}
+
+/// Parse one sensor and its closest beacon per line.
+pub fn parse_input(input: &str) -> Result<Vec<Pair>, ParseError> {
+    parse_lines(input.trim(), str::parse)
+}
+
+/// How many positions on `row` cannot contain a beacon.
+pub fn part_1(input: &[Pair], row: i32) -> usize {
+    let covered = input
+        .iter()
+        .filter_map(|pair| pair.sensor.range_on_row(pair.beacon, row))
+        .collect::<IntervalSet<_>>();
+    // Every beacon is covered by its own sensor, but the beacons we know about aren't missing.
+    let beacons = input
+        .iter()
+        .map(|pair| pair.beacon.point())
+        .filter(|point| point.y == row)
+        .collect::<HashSet<_>>();
+    covered.count() as usize - beacons.len()
+}
+
+/// The tuning frequency of the only position in the square from `0` to `size` that could hold the
+/// distress beacon.
+pub fn part_2(input: &[Pair], size: i32) -> i64 {
+    let target_range = Interval::new(0, size);
+    for y in 0..=size {
+        let mut ranges = IntervalSet::new();
+        for pair in input {
+            let sensor = pair.sensor;
+            let beacon = pair.beacon;
+            if let Some(range) = sensor.range_on_row(beacon, y) {
+                ranges.insert(range);
+            }
+        }
+        if ranges.contains_interval(&target_range) {
+            continue;
+        }
+
+        // We have a range that isn't completely filled. Let's now inefficiently run the entire row
+        // to see if there are open spots.
+        for x in 0..=size {
+            let point = Point { x, y };
+            if input.iter().any(|pair| pair.contains_point(point)) {
+                continue;
+            }
+
+            let missing_beacon = Beacon::from(point);
+            return missing_beacon.tuning_frequency();
+        }
+    }
+
+    panic!("Did not find point");
+}
+
+/// Where a sensor is.
+#[derive(Copy, Clone)]
+pub struct Sensor(Point);
+
+impl From<Point> for Sensor {
+    fn from(point: Point) -> Self {
+        Self(point)
+    }
+}
+
+impl Sensor {
+    /// Where the sensor is.
+    pub fn point(&self) -> Point {
+        self.0
+    }
+
+    fn distance_to_point(&self, point: Point) -> i32 {
+        self.point().manhattan_distance(point)
+    }
+
+    fn distance_to_beacon(&self, beacon: Beacon) -> i32 {
+        self.distance_to_point(beacon.point())
+    }
+
+    /// The positions on `row` no further from the sensor than `beacon`, if there are any.
+    fn range_on_row(&self, beacon: Beacon, row: i32) -> Option<Interval<i32>> {
+        let distance = self.distance_to_beacon(beacon);
+
+        let mut mid_point = self.point();
+        mid_point.y = row;
+        let distance_to_point = self.point().manhattan_distance(mid_point);
+        if distance_to_point > distance {
+            None
+        } else {
+            let diff = distance - distance_to_point;
+
+            let start_point = mid_point + Direction::West.vector() * diff;
+            let end_point = mid_point + Direction::East.vector() * diff;
+            Some(Interval::new(start_point.x, end_point.x))
+        }
+    }
+}
+
+/// Where a beacon is.
+#[derive(Copy, Clone)]
+pub struct Beacon(Point);
+
+impl From<Point> for Beacon {
+    fn from(point: Point) -> Self {
+        Self(point)
+    }
+}
+
+impl Beacon {
+    /// Where the beacon is.
+    pub fn point(&self) -> Point {
+        self.0
+    }
+
+    /// The beacon's `x` times 4000000, plus its `y`.
+    pub fn tuning_frequency(&self) -> i64 {
+        self.0.x as i64 * 4_000_000 + self.0.y as i64
+    }
+}
+
+/// A sensor and the closest beacon to it.
+pub struct Pair {
+    sensor: Sensor,
+    beacon: Beacon,
+}
+
+impl Pair {
+    /// A sensor whose closest beacon is `beacon`.
+    pub fn new(sensor: Sensor, beacon: Beacon) -> Self {
+        Self { sensor, beacon }
+    }
+
+    /// Whether `point` is no further from the sensor than its beacon, so can't hold another beacon.
+    pub fn contains_point(&self, point: Point) -> bool {
+        let distance_to_beacon = self.sensor.distance_to_beacon(self.beacon);
+        let distance_to_point = self.sensor.distance_to_point(point);
+        distance_to_point <= distance_to_beacon
+    }
+}
+
+impl FromStr for Pair {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts = s.split(' ').collect::<Vec<_>>();
+        if parts.len() != 10 {
+            return Err(ParseError::expected(
+                "`Sensor at x=<x>, y=<y>: closest beacon is at x=<x>, y=<y>`",
+                s,
+            ));
+        }
+
+        /// A coordinate written like `x=2,`.
+        fn coordinate(part: &str, prefix: &str, suffix: &str) -> Result<i32, ParseError> {
+            part.strip_prefix(prefix)
+                .and_then(|v| v.strip_suffix(suffix))
+                .and_then(|v| v.parse().ok())
+                .ok_or_else(|| ParseError::expected(format!("`{prefix}<n>{suffix}`"), part))
+        }
+
+        let sensor_x = coordinate(parts[2], "x=", ",")?;
+        let sensor_y = coordinate(parts[3], "y=", ":")?;
+        let beacon_x = coordinate(parts[8], "x=", ",")?;
+        let beacon_y = coordinate(parts[9], "y=", "")?;
+
+        let sensor = Point {
+            x: sensor_x,
+            y: sensor_y,
+        }
+        .into();
+        let beacon = Point {
+            x: beacon_x,
+            y: beacon_y,
+        }
+        .into();
+        Ok(Pair::new(sensor, beacon))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_range_on_row() {
+        // .........
+        // ....#....
+        // ...B##...
+        // ..#####..
+        // .###S###.
+        // ..#####.. <--
+        // ...###...
+        // ....#....
+        // .........
+        let sensor = Sensor::from(Point { x: 0, y: 0 });
+        let beacon = Beacon::from(Point { x: -1, y: -2 });
+        assert_eq!(sensor.range_on_row(beacon, 1), Some(Interval::new(-2, 2)));
+        assert_eq!(sensor.range_on_row(beacon, 3), Some(Interval::new(0, 0)));
+        assert_eq!(sensor.range_on_row(beacon, 4), None);
+    }
+
+    #[test]
+    fn test_ranges() {
+        let mut ranges = IntervalSet::new();
+        ranges.insert(Interval::new(0, 2));
+        ranges.insert(Interval::new(4, 6));
+        ranges.insert(Interval::new(2, 4));
+        assert_eq!(ranges.as_slice(), [Interval::new(0, 6)]);
+    }
+
+    aoc_core::example_tests! {
+        test_part_1: Day15 { row: 10, size: 20 }, "example.txt", part_1 => 26;
+        test_part_2: Day15 { row: 10, size: 20 }, "example.txt", part_2 => 56_000_011;
+    }
+}
@@ -0,0 +1,42 @@
+//! Random lava droplets: distinct cubes packed closely enough to leave air pockets inside.
+
+use aoc_core::random::Rng;
+use std::collections::HashSet;
+
+/// `size` distinct cubes in a box just big enough to hold them.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    let mut side = 1;
+    while side * side * side < 2 * size {
+        side += 1;
+    }
+    let side = side as i64;
+
+    let mut cubes = HashSet::new();
+    let mut input = String::new();
+    while cubes.len() < size {
+        let cube = (
+            rng.range(0..=side - 1),
+            rng.range(0..=side - 1),
+            rng.range(0..=side - 1),
+        );
+        if cubes.insert(cube) {
+            input.push_str(&format!("{},{},{}\n", cube.0, cube.1, cube.2));
+        }
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_input;
+
+    #[test]
+    fn test_distinct() {
+        let input = input(&mut Rng::new(18), 50);
+        let cubes = parse_input(&input).unwrap();
+        assert_eq!(cubes.len(), 50);
+        assert_eq!(cubes.iter().collect::<HashSet<_>>().len(), 50);
+    }
+}
@@ -0,0 +1,149 @@
+//! Day 18: Boiling Boulders.
+
+pub mod generate;
+
+use aoc_core::{
+    parse_lines,
+    random::Rng,
+    stream::{self, StreamError},
+    Day, ParseError, StreamingDay,
+};
+use geometry::{Bounds3, Point3};
+use std::{collections::HashSet, io::BufRead};
+
+/// Boiling Boulders: measure the surface area of a droplet of lava made of 1x1x1 cubes.
+pub struct Day18;
+
+impl Day for Day18 {
+    const DAY: u8 = 18;
+    type Input<'a> = Vec<Point>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>) -> Self::Part1 {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>) -> Self::Part2 {
+        part_2(input)
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+}
+
+/// The surface area is added up as each cube arrives. The droplet itself still has to be kept for
+/// part 2, but not the list of cubes as well.
+impl StreamingDay for Day18 {
+    fn solve_stream(&self, reader: impl BufRead) -> Result<(usize, usize), StreamError> {
+        let mut droplet = HashSet::new();
+        let mut surface_area = 0;
+        let lines = stream::parse_lines(reader, |line| {
+            (!line.is_empty()).then(|| parse_cube(line)).transpose()
+        });
+        for cube in lines {
+            let Some(cube) = cube? else {
+                continue;
+            };
+            if droplet.insert(cube) {
+                let touching = cube
+                    .neighbours()
+                    .filter(|neighbour| droplet.contains(neighbour))
+                    .count();
+                surface_area = surface_area + 6 - 2 * touching;
+            }
+        }
+        Ok((surface_area, exterior_surface_area(&droplet)))
+    }
+}
+
+/// Parse one cube per line, written `x,y,z`.
+pub fn parse_input(input: &str) -> Result<Vec<Point>, ParseError> {
+    parse_lines(input.trim(), parse_cube)
+}
+
+fn parse_cube(line: &str) -> Result<Point, ParseError> {
+    line.parse()
+        .map_err(|err| ParseError::expected(format!("a cube like `1,2,3` ({err})"), line))
+}
+
+/// The surface area of the droplet, counting every face that doesn't touch another cube.
+pub fn part_1(input: &[Point]) -> usize {
+    let droplet = input.iter().copied().collect::<HashSet<Point>>();
+    let total_sides = input.len() * 6;
+    let mut adjacent_sides = 0;
+    for point in input {
+        for adjacent_point in point.neighbours() {
+            if droplet.contains(&adjacent_point) {
+                adjacent_sides += 1;
+            }
+        }
+    }
+    total_sides - adjacent_sides
+}
+
+/// The exterior surface area of the droplet, leaving out the faces of air pockets trapped inside.
+pub fn part_2(input: &[Point]) -> usize {
+    let droplet = input.iter().copied().collect::<HashSet<Point>>();
+    exterior_surface_area(&droplet)
+}
+
+/// Count the faces of the droplet that steam spreading from outside of it can reach.
+fn exterior_surface_area(droplet: &HashSet<Point>) -> usize {
+    // Leave a layer of air all the way around the droplet, so that the steam can reach every side.
+    let Some(bounds) = Bounds3::from_points(droplet.iter().copied()) else {
+        return 0;
+    };
+    let bounds = bounds.grow(1);
+
+    let mut count = 0;
+
+    let mut seen = HashSet::new();
+    let mut stack = vec![bounds.min];
+
+    while let Some(item) = stack.pop() {
+        if seen.contains(&item) {
+            continue;
+        }
+        seen.insert(item);
+        for adjacent in item
+            .neighbours()
+            .filter(|adjacent| bounds.contains(*adjacent))
+        {
+            if droplet.contains(&adjacent) {
+                count += 1;
+            } else {
+                stack.push(adjacent);
+            }
+        }
+    }
+
+    count
+}
+
+/// A 1x1x1 cube of lava.
+pub type Point = Point3;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve_stream() {
+        let input = include_str!("example.txt");
+        assert_eq!(Day18.solve_stream(input.as_bytes()).unwrap(), (64, 58));
+        // The same cube twice is still one cube.
+        let input = "1,1,1\n2,1,1\n1,1,1\n";
+        assert_eq!(Day18.solve_stream(input.as_bytes()).unwrap(), (10, 10));
+    }
+
+    aoc_core::example_tests! {
+        test_part_1: Day18, "example.txt", part_1 => 64;
+        test_part_2: Day18, "example.txt", part_2 => 58;
+    }
+}
@@ -0,0 +1,149 @@
+//! Random troops of monkeys. Like the real ones, every division comes out exact, `humn` is used
+//! exactly once, and there's a whole number it can yell to make both sides of `root` equal.
+
+use aoc_core::random::Rng;
+use std::collections::HashSet;
+
+/// A troop where `humn` is up to `size` monkeys below `root`.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let mut troop = Troop::default();
+    let target = rng.range(0..=1000);
+    let depth = 1 + rng.below(size.clamp(1, 10));
+    let human = troop.depends_on_humn(rng, target, depth);
+    let other = troop.yells(rng, target, 3);
+    let (left, right) = if rng.chance(0.5) {
+        (human, other)
+    } else {
+        (other, human)
+    };
+    let op = rng.choose(&['+', '-', '*']);
+    troop.monkeys.push(format!("root: {left} {op} {right}"));
+
+    // Dividing by something that depends on `humn` only comes out exact for the answer, so yell
+    // that for part one too.
+    let humn = if troop.divides_by_humn {
+        troop.answer
+    } else {
+        rng.range(0..=1000)
+    };
+    troop.monkeys.push(format!("humn: {humn}"));
+
+    rng.shuffle(&mut troop.monkeys);
+    troop.monkeys.join("\n") + "\n"
+}
+
+#[derive(Default)]
+struct Troop {
+    monkeys: Vec<String>,
+    names: HashSet<String>,
+    /// What `humn` needs to yell for part two.
+    answer: i64,
+    divides_by_humn: bool,
+}
+
+impl Troop {
+    /// A monkey that yells `value` without listening to `humn`, up to `depth` monkeys deep.
+    fn yells(&mut self, rng: &mut Rng, value: i64, depth: usize) -> String {
+        // The real monkeys only ever yell numbers that aren't negative.
+        if value >= 0 && (depth == 0 || rng.chance(0.3)) {
+            return self.monkey(rng, value.to_string());
+        }
+        let depth = depth.saturating_sub(1);
+        let divisors = (2..=5).filter(|d| value % d == 0).collect::<Vec<_>>();
+        let (left, op, right) = if value < 0 {
+            let left = rng.range(0..=10);
+            (left, '-', left - value)
+        } else {
+            match rng.below(4) {
+                0 => {
+                    let left = rng.range(0..=value);
+                    (left, '+', value - left)
+                }
+                1 => {
+                    let right = rng.range(0..=10);
+                    (value + right, '-', right)
+                }
+                2 if !divisors.is_empty() => {
+                    let right = *rng.choose(&divisors);
+                    (value / right, '*', right)
+                }
+                _ => {
+                    let right = rng.range(1..=5);
+                    (value * right, '/', right)
+                }
+            }
+        };
+        let left = self.yells(rng, left, depth);
+        let right = self.yells(rng, right, depth);
+        self.monkey(rng, format!("{left} {op} {right}"))
+    }
+
+    /// A monkey that yells `value` when `humn` yells the answer, with `humn` `depth` monkeys
+    /// below it.
+    fn depends_on_humn(&mut self, rng: &mut Rng, value: i64, depth: usize) -> String {
+        if depth == 0 {
+            self.answer = value;
+            return String::from("humn");
+        }
+        let constant = rng.range(0..=10);
+        let divisors = (2..=5).filter(|d| value % d == 0).collect::<Vec<_>>();
+        // Each job is written with the side that depends on `humn` first, then flipped where
+        // the operation allows it.
+        let (human, op, constant, flip) = match rng.below(5) {
+            0 => (value - constant, '+', constant, rng.chance(0.5)),
+            1 => (value + constant, '-', constant, false),
+            2 => (constant - value, '-', constant, true),
+            3 if !divisors.is_empty() => {
+                let constant = *rng.choose(&divisors);
+                (value / constant, '*', constant, rng.chance(0.5))
+            }
+            _ if value != 0 && rng.chance(0.1) => {
+                // Rare, but nothing in the puzzle rules it out.
+                self.divides_by_humn = true;
+                let divisor = rng.range(1..=5);
+                (divisor, '/', value * divisor, true)
+            }
+            _ => {
+                let constant = rng.range(1..=5);
+                (value * constant, '/', constant, false)
+            }
+        };
+        let human = self.depends_on_humn(rng, human, depth - 1);
+        let constant = self.yells(rng, constant, 3);
+        let job = if flip {
+            format!("{constant} {op} {human}")
+        } else {
+            format!("{human} {op} {constant}")
+        };
+        self.monkey(rng, job)
+    }
+
+    /// Add a monkey with a new name and the given job, and return its name.
+    fn monkey(&mut self, rng: &mut Rng, job: String) -> String {
+        let name = loop {
+            let name = (0..4)
+                .map(|_| (b'a' + rng.below(26) as u8) as char)
+                .collect::<String>();
+            if name != "root" && name != "humn" && self.names.insert(name.clone()) {
+                break name;
+            }
+        };
+        self.monkeys.push(format!("{name}: {job}"));
+        name
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, part_1};
+
+    #[test]
+    fn test_parses() {
+        for seed in 0..20 {
+            let input = input(&mut Rng::new(seed), 10);
+            let troop = parse(&input).unwrap();
+            part_1(&troop);
+        }
+    }
+}
@@ -0,0 +1,384 @@
+//! Day 21: Monkey Math.
+
+pub mod generate;
+
+use aoc_core::{random::Rng, Day, ParseError};
+use nom::{
+    branch::alt,
+    bytes::complete::{tag, take_while1},
+    character::complete::{i64, space0},
+    combinator::{map, opt},
+    multi::separated_list0,
+    sequence::{preceded, terminated, tuple},
+    IResult,
+};
+use std::{collections::HashMap, fmt::Debug};
+
+/// Monkey Math: work out what the monkeys yell, and what you need to yell.
+pub struct Day21;
+
+impl Day for Day21 {
+    const DAY: u8 = 21;
+    type Input<'a> = Troop<'a>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>) -> Self::Part1 {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>) -> Self::Part2 {
+        part_2(input)
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+}
+
+/// The number the monkey named `root` yells.
+pub fn part_1(troop: &Troop<'_>) -> i64 {
+    let root = troop.get("root").unwrap();
+    root.expr.eval(troop).unwrap()
+}
+
+/// The number you (`humn`) need to yell so that both sides of `root`'s equation are equal.
+pub fn part_2(troop: &Troop<'_>) -> i64 {
+    let mut troop = troop.clone();
+    let humn = troop.get_mut("humn").unwrap();
+    humn.expr = Expr::Unknown;
+    let root = troop.get("root").unwrap();
+    let Expr::Op(_, ref expr1, ref expr2) = root.expr else {
+        panic!("Not an op")
+    };
+    let expr1 = expr1.no_variable_expression(&troop).unwrap();
+    let expr2 = expr2.no_variable_expression(&troop).unwrap();
+    let expr = NoVariableExpression::Op(Op::Sub, Box::new(expr1), Box::new(expr2));
+    expr.solve(0).unwrap()
+}
+
+/// Every monkey, by name.
+#[derive(Clone)]
+pub struct Troop<'a>(HashMap<&'a str, Monkey<'a>>);
+
+impl<'a> Troop<'a> {
+    /// The monkey called `id`, if there is one.
+    pub fn get(&self, id: &'a str) -> Option<&Monkey<'a>> {
+        self.0.get(id)
+    }
+
+    /// The monkey called `id`, if there is one, to change what it yells.
+    pub fn get_mut(&mut self, id: &'a str) -> Option<&mut Monkey<'a>> {
+        self.0.get_mut(id)
+    }
+}
+
+/// What a monkey yells.
+#[derive(Debug, Clone)]
+pub enum Expr<'a> {
+    /// A number.
+    Const(i64),
+    /// Whatever the named monkey yells.
+    Var(&'a str),
+    /// The result of an operation on two other expressions.
+    Op(Op, Box<Expr<'a>>, Box<Expr<'a>>),
+    /// The number to solve for, like `humn` in part 2.
+    Unknown,
+}
+
+impl<'a> Expr<'a> {
+    /// The number this expression comes to, looking other monkeys up in `troop`.
+    pub fn eval(&self, troop: &Troop<'a>) -> Result<i64, ExprError<'a>> {
+        match self {
+            Expr::Const(val) => Ok(*val),
+            Expr::Var(var) => {
+                let monkey = troop.get(var).ok_or(ExprError::VarNotFound(var))?;
+                monkey.expr.eval(troop)
+            }
+            Expr::Op(op, expr1, expr2) => {
+                let val1 = expr1.eval(troop)?;
+                let val2 = expr2.eval(troop)?;
+                Ok(op.eval(val1, val2))
+            }
+            Expr::Unknown => Err(ExprError::CantEvaluateUnknown),
+        }
+    }
+
+    /// This expression with every monkey's name replaced by what it yells, and every part that
+    /// doesn't depend on [`Expr::Unknown`] worked out.
+    pub fn no_variable_expression(
+        &self,
+        troop: &Troop<'a>,
+    ) -> Result<NoVariableExpression, ExprError<'a>> {
+        match self {
+            Expr::Const(val) => Ok(NoVariableExpression::Const(*val)),
+            Expr::Var(var) => {
+                let monkey = troop.get(var).ok_or(ExprError::VarNotFound(var))?;
+                monkey.expr.no_variable_expression(troop)
+            }
+            Expr::Op(op, expr1, expr2) => {
+                let val1 = expr1.no_variable_expression(troop)?;
+                let val2 = expr2.no_variable_expression(troop)?;
+
+                match (val1, val2) {
+                    (NoVariableExpression::Const(v1), NoVariableExpression::Const(v2)) => {
+                        Ok(NoVariableExpression::Const(op.eval(v1, v2)))
+                    }
+                    (val1, val2) => Ok(NoVariableExpression::Op(
+                        *op,
+                        Box::new(val1),
+                        Box::new(val2),
+                    )),
+                }
+            }
+            Expr::Unknown => Ok(NoVariableExpression::Unknown),
+        }
+    }
+}
+
+/// Why a monkey's expression couldn't be evaluated.
+#[derive(Debug)]
+pub enum ExprError<'a> {
+    /// No monkey has this name.
+    VarNotFound(&'a str),
+    /// The expression depends on [`Expr::Unknown`], so it has no single value.
+    CantEvaluateUnknown,
+}
+
+/// An expression that doesn't refer to any monkeys, so it can be solved on its own.
+pub enum NoVariableExpression {
+    /// A number.
+    Const(i64),
+    /// The result of an operation on two other expressions.
+    Op(Op, Box<NoVariableExpression>, Box<NoVariableExpression>),
+    /// The number to solve for.
+    Unknown,
+}
+
+impl Debug for NoVariableExpression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Const(arg0) => arg0.fmt(f),
+            Self::Op(arg0, arg1, arg2) => {
+                f.write_str("(")?;
+                arg1.fmt(f)?;
+                std::fmt::Display::fmt(arg0, f)?;
+                arg2.fmt(f)?;
+                f.write_str(")")?;
+                Ok(())
+            }
+            Self::Unknown => f.write_str("x"),
+        }
+    }
+}
+
+impl NoVariableExpression {
+    /// The value of [`NoVariableExpression::Unknown`] that makes this expression come to `val`.
+    pub fn solve(&self, val: i64) -> Result<i64, SolveError> {
+        match self {
+            NoVariableExpression::Const(_) => Err(SolveError::SolvingAConstant),
+            NoVariableExpression::Op(op, expr1, expr2) => match (expr1.as_ref(), expr2.as_ref()) {
+                (NoVariableExpression::Const(c), expr2) => {
+                    // 8 = 2 % x
+                    match op {
+                        Op::Add => expr2.solve(val - c),
+                        Op::Sub => {
+                            // 8 = 2 - x
+                            // 8 - 2 = -x
+                            // -(8 - 2) = x
+                            expr2.solve(-(val - c))
+                        }
+                        Op::Mul => expr2.solve(val / c),
+                        Op::Div => todo!("8 = 2 / x"),
+                    }
+                }
+                (expr1, NoVariableExpression::Const(c)) => {
+                    // 8 = x % 2
+                    match op {
+                        Op::Add => expr1.solve(val - c),
+                        Op::Sub => expr1.solve(val + c),
+                        Op::Mul => expr1.solve(val / c),
+                        Op::Div => expr1.solve(val * c),
+                    }
+                }
+                _ => Err(SolveError::UnknownOnBothSides),
+            },
+            NoVariableExpression::Unknown => Ok(val),
+        }
+    }
+}
+
+/// Why an expression couldn't be solved.
+#[derive(Debug)]
+pub enum SolveError {
+    /// Both sides of an operation depend on the unknown.
+    UnknownOnBothSides,
+    /// The expression doesn't depend on the unknown at all.
+    SolvingAConstant,
+}
+
+/// An arithmetic operation a monkey can do.
+#[derive(Debug, Copy, Clone)]
+pub enum Op {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+impl Op {
+    /// `val1` and `val2` with this operation applied.
+    pub fn eval(&self, val1: i64, val2: i64) -> i64 {
+        match self {
+            Op::Add => val1 + val2,
+            Op::Sub => val1 - val2,
+            Op::Mul => val1 * val2,
+            Op::Div => val1 / val2,
+        }
+    }
+}
+
+impl std::fmt::Display for Op {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Op::Add => f.write_str("+"),
+            Op::Sub => f.write_str("-"),
+            Op::Mul => f.write_str("*"),
+            Op::Div => f.write_str("/"),
+        }
+    }
+}
+
+/// A monkey, and what it yells.
+#[derive(Debug, Clone)]
+pub struct Monkey<'a> {
+    /// The monkey's name.
+    pub id: &'a str,
+    /// What the monkey yells.
+    pub expr: Expr<'a>,
+}
+
+/// Parse one monkey per line, like `root: pppw + sjmn` or `dbpl: 5`.
+pub fn parse(input: &str) -> Result<Troop<'_>, ParseError> {
+    const EXPECTED: &str = "a monkey like `root: pppw + sjmn` or `dbpl: 5`";
+    let (rest, troop) = parse_troop(input).map_err(|err| match err {
+        nom::Err::Error(err) | nom::Err::Failure(err) => {
+            ParseError::expected_at(EXPECTED, err.input)
+        }
+        nom::Err::Incomplete(_) => ParseError::expected_at(EXPECTED, ""),
+    })?;
+    if !rest.is_empty() {
+        // The list of monkeys stops at the first line that isn't one.
+        let line = rest
+            .trim_start_matches('\n')
+            .lines()
+            .next()
+            .unwrap_or_default();
+        return Err(ParseError::expected(EXPECTED, line));
+    }
+
+    Ok(troop)
+}
+
+fn parse_troop(input: &str) -> IResult<&str, Troop<'_>> {
+    let (rest, monkeys) =
+        terminated(separated_list0(tag("\n"), parse_monkey), opt(tag("\n")))(input)?;
+    let hashmap = monkeys
+        .into_iter()
+        .map(|monkey| (monkey.id, monkey))
+        .collect();
+    Ok((rest, Troop(hashmap)))
+}
+
+fn parse_monkey(input: &str) -> IResult<&str, Monkey<'_>> {
+    let (rest, id) = parse_id(input)?;
+    let (rest, _) = tag(":")(rest)?;
+    let (rest, expr) = preceded(space0, parse_expr)(rest)?;
+    let monkey = Monkey { id, expr };
+
+    Ok((rest, monkey))
+}
+
+fn parse_expr(input: &str) -> IResult<&str, Expr<'_>> {
+    alt((parse_const_expr, parse_operation))(input)
+}
+
+fn parse_const_expr(input: &str) -> IResult<&str, Expr<'_>> {
+    map(parse_const, Expr::Const)(input)
+}
+
+fn parse_const(input: &str) -> IResult<&str, i64> {
+    i64(input)
+}
+
+fn parse_operation(input: &str) -> IResult<&str, Expr<'_>> {
+    let (rest, (id1, op, id2)) = tuple((
+        parse_var_expr,
+        preceded(space0, parse_op),
+        preceded(space0, parse_var_expr),
+    ))(input)?;
+    let expr = Expr::Op(op, Box::new(id1), Box::new(id2));
+    Ok((rest, expr))
+}
+
+fn parse_var_expr(input: &str) -> IResult<&str, Expr<'_>> {
+    map(parse_id, Expr::Var)(input)
+}
+
+fn parse_id(input: &str) -> IResult<&str, &str> {
+    take_while1(char::is_alphabetic)(input)
+}
+
+fn parse_op(input: &str) -> IResult<&str, Op> {
+    alt((
+        map(tag("+"), |_| Op::Add),
+        map(tag("-"), |_| Op::Sub),
+        map(tag("*"), |_| Op::Mul),
+        map(tag("/"), |_| Op::Div),
+    ))(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_2_makes_sense() {
+        let input = include_str!("example.txt");
+
+        let mut troop = parse(input).unwrap();
+        let humn = troop.get_mut("humn").unwrap();
+        humn.expr = Expr::Unknown;
+        let root = troop.get("root").unwrap();
+        let Expr::Op(_, ref expr1, ref expr2) = root.expr else {
+            panic!("Not an op")
+        };
+        let expr1 = expr1.no_variable_expression(&troop).unwrap();
+        let expr2 = expr2.no_variable_expression(&troop).unwrap();
+        let expr = NoVariableExpression::Op(Op::Sub, Box::new(expr1), Box::new(expr2));
+        let solved = expr.solve(0).unwrap();
+
+        let humn = troop.get_mut("humn").unwrap();
+        humn.expr = Expr::Const(solved);
+        let root = troop.get_mut("root").unwrap();
+        let prev_expr = std::mem::replace(&mut root.expr, Expr::Unknown);
+        if let Expr::Op(_, expr1, expr2) = prev_expr {
+            root.expr = Expr::Op(Op::Sub, expr1, expr2);
+        } else {
+            root.expr = prev_expr;
+        }
+
+        let root = troop.get("root").unwrap();
+        let result = root.expr.eval(&troop).unwrap();
+        assert_eq!(result, 0);
+    }
+
+    aoc_core::example_tests! {
+        test_part_1: Day21, "example.txt", part_1 => 152;
+        test_part_2: Day21, "example.txt", part_2 => 301;
+    }
+}
@@ -0,0 +1,63 @@
+//! Random boards folded from a cube net, and paths to follow around them.
+
+use aoc_core::random::Rng;
+
+/// Where the six faces of the cube sit, in a few of the ways a cube can be unfolded. The first
+/// is the example's, the second the real input's.
+const NETS: [&[&str]; 3] = [
+    &["..#", "###", "..##"],
+    &[".##", ".#", "##", "#"],
+    &[".#", "####", ".#"],
+];
+
+/// A board with faces up to `size` tiles wide, and a path of `size` moves and turns.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    let face = 2 + rng.below(size);
+    let net = rng.choose(&NETS);
+
+    let mut rows = Vec::new();
+    for faces in net.iter() {
+        for _ in 0..face {
+            let mut row = String::new();
+            for kind in faces.chars() {
+                for _ in 0..face {
+                    row.push(match kind {
+                        '.' => ' ',
+                        _ if rng.chance(0.1) => '#',
+                        _ => '.',
+                    });
+                }
+            }
+            rows.push(row);
+        }
+    }
+    // The path starts at the leftmost open tile of the top row, so there has to be one.
+    let start = rows[0].len() - rows[0].trim_start().len();
+    rows[0].replace_range(start..start + 1, ".");
+
+    let mut input = rows.join("\n");
+    input.push_str("\n\n");
+    input.push_str(&rng.range(1..=4 * face as i64).to_string());
+    for _ in 0..size {
+        input.push(*rng.choose(&['L', 'R']));
+        input.push_str(&rng.range(1..=4 * face as i64).to_string());
+    }
+    input.push('\n');
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, part_1};
+
+    #[test]
+    fn test_parses() {
+        for seed in 0..20 {
+            let input = input(&mut Rng::new(seed), 10);
+            let parsed = parse(&input).unwrap();
+            part_1(&parsed);
+        }
+    }
+}
@@ -0,0 +1,168 @@
+use crate::{coordinate::inclusive, Coordinate, Point, Point3};
+
+/// A rectangle on a grid, edges included.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Bounds<T = i32> {
+    pub min: Point<T>,
+    pub max: Point<T>,
+}
+
+impl<T: Coordinate> Bounds<T> {
+    /// The bounds of a single point.
+    pub fn from_point(point: Point<T>) -> Self {
+        Self {
+            min: point,
+            max: point,
+        }
+    }
+
+    /// The smallest bounds that hold every point, or `None` if there are none.
+    pub fn from_points(points: impl IntoIterator<Item = Point<T>>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let mut bounds = Self::from_point(points.next()?);
+        for point in points {
+            bounds.include(point);
+        }
+        Some(bounds)
+    }
+
+    /// Grow the bounds, if needed, so that they hold `point`.
+    pub fn include(&mut self, point: Point<T>) {
+        self.min.x = std::cmp::min(self.min.x, point.x);
+        self.min.y = std::cmp::min(self.min.y, point.y);
+        self.max.x = std::cmp::max(self.max.x, point.x);
+        self.max.y = std::cmp::max(self.max.y, point.y);
+    }
+
+    pub fn contains(&self, point: Point<T>) -> bool {
+        (self.min.x <= point.x && point.x <= self.max.x)
+            && (self.min.y <= point.y && point.y <= self.max.y)
+    }
+
+    /// The bounds with `amount` more room on every side.
+    pub fn grow(self, amount: T) -> Self {
+        Self {
+            min: Point::new(self.min.x - amount, self.min.y - amount),
+            max: Point::new(self.max.x + amount, self.max.y + amount),
+        }
+    }
+
+    pub fn width(&self) -> T {
+        self.max.x - self.min.x + T::ONE
+    }
+
+    pub fn height(&self) -> T {
+        self.max.y - self.min.y + T::ONE
+    }
+
+    /// Every point in the bounds, a row at a time.
+    pub fn points(&self) -> impl Iterator<Item = Point<T>> {
+        let Bounds { min, max } = *self;
+        inclusive(min.y, max.y)
+            .flat_map(move |y| inclusive(min.x, max.x).map(move |x| Point { x, y }))
+    }
+}
+
+/// A box in space, faces included.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Bounds3<T = i32> {
+    pub min: Point3<T>,
+    pub max: Point3<T>,
+}
+
+impl<T: Coordinate> Bounds3<T> {
+    /// The bounds of a single point.
+    pub fn from_point(point: Point3<T>) -> Self {
+        Self {
+            min: point,
+            max: point,
+        }
+    }
+
+    /// The smallest bounds that hold every point, or `None` if there are none.
+    pub fn from_points(points: impl IntoIterator<Item = Point3<T>>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let mut bounds = Self::from_point(points.next()?);
+        for point in points {
+            bounds.include(point);
+        }
+        Some(bounds)
+    }
+
+    /// Grow the bounds, if needed, so that they hold `point`.
+    pub fn include(&mut self, point: Point3<T>) {
+        self.min.x = std::cmp::min(self.min.x, point.x);
+        self.min.y = std::cmp::min(self.min.y, point.y);
+        self.min.z = std::cmp::min(self.min.z, point.z);
+        self.max.x = std::cmp::max(self.max.x, point.x);
+        self.max.y = std::cmp::max(self.max.y, point.y);
+        self.max.z = std::cmp::max(self.max.z, point.z);
+    }
+
+    pub fn contains(&self, point: Point3<T>) -> bool {
+        (self.min.x <= point.x && point.x <= self.max.x)
+            && (self.min.y <= point.y && point.y <= self.max.y)
+            && (self.min.z <= point.z && point.z <= self.max.z)
+    }
+
+    /// The bounds with `amount` more room on every side.
+    pub fn grow(self, amount: T) -> Self {
+        Self {
+            min: Point3::new(
+                self.min.x - amount,
+                self.min.y - amount,
+                self.min.z - amount,
+            ),
+            max: Point3::new(
+                self.max.x + amount,
+                self.max.y + amount,
+                self.max.z + amount,
+            ),
+        }
+    }
+
+    pub fn width(&self) -> T {
+        self.max.x - self.min.x + T::ONE
+    }
+
+    pub fn height(&self) -> T {
+        self.max.y - self.min.y + T::ONE
+    }
+
+    pub fn depth(&self) -> T {
+        self.max.z - self.min.z + T::ONE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bounds() {
+        assert_eq!(Bounds::<i32>::from_points([]), None);
+
+        let bounds =
+            Bounds::from_points([Point::new(3, -1), Point::new(0, 2), Point::new(1, 1)]).unwrap();
+        assert_eq!(bounds.min, Point::new(0, -1));
+        assert_eq!(bounds.max, Point::new(3, 2));
+        assert_eq!(bounds.width(), 4);
+        assert_eq!(bounds.height(), 4);
+        assert!(bounds.contains(Point::new(3, 2)));
+        assert!(!bounds.contains(Point::new(4, 2)));
+        assert!(bounds.grow(1).contains(Point::new(4, 3)));
+        assert_eq!(bounds.points().count(), 16);
+        assert_eq!(bounds.points().next(), Some(Point::new(0, -1)));
+    }
+
+    #[test]
+    fn test_bounds3() {
+        let bounds = Bounds3::from_points([Point3::new(1, 2, 3), Point3::new(2, 2, 6)]).unwrap();
+        assert_eq!(bounds.width(), 2);
+        assert_eq!(bounds.height(), 1);
+        assert_eq!(bounds.depth(), 4);
+        assert!(bounds.contains(Point3::new(2, 2, 4)));
+        assert!(!bounds.contains(Point3::new(0, 2, 4)));
+        assert!(bounds.grow(1).contains(Point3::new(0, 1, 7)));
+    }
+}
@@ -0,0 +1,55 @@
+use std::{
+    fmt::Debug,
+    hash::Hash,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+/// A signed integer that points and vectors can be made of.
+pub trait Coordinate:
+    Copy
+    + Debug
+    + Default
+    + Eq
+    + Ord
+    + Hash
+    + Add<Output = Self>
+    + AddAssign
+    + Sub<Output = Self>
+    + SubAssign
+    + Mul<Output = Self>
+    + Neg<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn abs(self) -> Self;
+    fn signum(self) -> Self;
+}
+
+macro_rules! impl_coordinate {
+    ($($ty:ty),*) => {
+        $(
+            impl Coordinate for $ty {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn abs(self) -> Self {
+                    <$ty>::abs(self)
+                }
+
+                fn signum(self) -> Self {
+                    <$ty>::signum(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_coordinate!(i8, i16, i32, i64, isize);
+
+/// Every value from `start` to `end`, inclusive.
+pub(crate) fn inclusive<T: Coordinate>(start: T, end: T) -> impl Iterator<Item = T> {
+    std::iter::successors((start <= end).then_some(start), move |&value| {
+        (value < end).then(|| value + T::ONE)
+    })
+}
@@ -0,0 +1,147 @@
+use crate::{Coordinate, Vector};
+use std::ops::{Add, AddAssign};
+
+/// One of the eight directions on a grid, cardinal and diagonal.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction {
+    /// The four cardinal directions, clockwise from north.
+    pub const CARDINAL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    /// The four diagonal directions, clockwise from north-east.
+    pub const DIAGONAL: [Direction; 4] = [
+        Direction::NorthEast,
+        Direction::SouthEast,
+        Direction::SouthWest,
+        Direction::NorthWest,
+    ];
+
+    /// All eight directions, clockwise from north.
+    pub const ALL: [Direction; 8] = [
+        Direction::North,
+        Direction::NorthEast,
+        Direction::East,
+        Direction::SouthEast,
+        Direction::South,
+        Direction::SouthWest,
+        Direction::West,
+        Direction::NorthWest,
+    ];
+
+    pub fn is_cardinal(self) -> bool {
+        matches!(
+            self,
+            Direction::North | Direction::East | Direction::South | Direction::West
+        )
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        !self.is_cardinal()
+    }
+
+    /// A single step in this direction. Diagonal steps move along both axes.
+    pub fn vector<T: Coordinate>(self) -> Vector<T> {
+        let (x, y) = match self {
+            Direction::North => (T::ZERO, -T::ONE),
+            Direction::NorthEast => (T::ONE, -T::ONE),
+            Direction::East => (T::ONE, T::ZERO),
+            Direction::SouthEast => (T::ONE, T::ONE),
+            Direction::South => (T::ZERO, T::ONE),
+            Direction::SouthWest => (-T::ONE, T::ONE),
+            Direction::West => (-T::ONE, T::ZERO),
+            Direction::NorthWest => (-T::ONE, -T::ONE),
+        };
+        Vector { x, y }
+    }
+
+    /// Turn 90 degrees counter-clockwise.
+    pub fn turn_left(self) -> Self {
+        self.rotate(6)
+    }
+
+    /// Turn 90 degrees clockwise.
+    pub fn turn_right(self) -> Self {
+        self.rotate(2)
+    }
+
+    /// The direction pointing the other way.
+    pub fn opposite(self) -> Self {
+        self.rotate(4)
+    }
+
+    /// Rotate clockwise by `eighths` of a full turn.
+    fn rotate(self, eighths: usize) -> Self {
+        let index = Self::ALL.iter().position(|d| *d == self).unwrap();
+        Self::ALL[(index + eighths) % 8]
+    }
+}
+
+/// Which way to turn, relative to the direction you're facing.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Turn {
+    Left,
+    Right,
+    Around,
+}
+
+impl Add<Turn> for Direction {
+    type Output = Direction;
+
+    fn add(self, rhs: Turn) -> Self::Output {
+        match rhs {
+            Turn::Left => self.turn_left(),
+            Turn::Right => self.turn_right(),
+            Turn::Around => self.opposite(),
+        }
+    }
+}
+
+impl AddAssign<Turn> for Direction {
+    fn add_assign(&mut self, rhs: Turn) {
+        *self = *self + rhs;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_turns() {
+        assert_eq!(Direction::North + Turn::Left, Direction::West);
+        assert_eq!(Direction::North + Turn::Right, Direction::East);
+        assert_eq!(Direction::West + Turn::Right, Direction::North);
+        assert_eq!(Direction::SouthEast + Turn::Right, Direction::SouthWest);
+        assert_eq!(Direction::NorthWest + Turn::Around, Direction::SouthEast);
+
+        let mut direction = Direction::East;
+        direction += Turn::Left;
+        assert_eq!(direction, Direction::North);
+    }
+
+    #[test]
+    fn test_vectors() {
+        for direction in Direction::ALL {
+            let vector = direction.vector::<i32>();
+            assert_eq!(vector + direction.opposite().vector(), Vector::ZERO);
+            assert_eq!(direction.is_cardinal(), vector.manhattan_length() == 1);
+        }
+        assert_eq!(Direction::North.vector(), Vector { x: 0, y: -1 });
+        assert_eq!(Direction::SouthWest.vector(), Vector { x: -1, y: 1 });
+    }
+}
@@ -0,0 +1,157 @@
+//! Points, vectors, and directions for the days whose puzzles happen on a grid or in space.
+//!
+//! Like the rows of a puzzle input, `y` grows downward: [`Direction::North`] is toward smaller `y`.
+
+/// The arithmetic shared by the 2D and 3D types: a point moves by a vector, the difference of two
+/// points is a vector, and vectors add, subtract, negate, and scale.
+macro_rules! impl_ops {
+    ($point:ident, $vector:ident, $($field:ident),+) => {
+        impl<T: Coordinate> std::ops::Add<$vector<T>> for $point<T> {
+            type Output = $point<T>;
+
+            fn add(self, rhs: $vector<T>) -> Self::Output {
+                $point { $($field: self.$field + rhs.$field),+ }
+            }
+        }
+
+        impl<T: Coordinate> std::ops::AddAssign<$vector<T>> for $point<T> {
+            fn add_assign(&mut self, rhs: $vector<T>) {
+                $(self.$field += rhs.$field;)+
+            }
+        }
+
+        impl<T: Coordinate> std::ops::Sub<$vector<T>> for $point<T> {
+            type Output = $point<T>;
+
+            fn sub(self, rhs: $vector<T>) -> Self::Output {
+                $point { $($field: self.$field - rhs.$field),+ }
+            }
+        }
+
+        impl<T: Coordinate> std::ops::SubAssign<$vector<T>> for $point<T> {
+            fn sub_assign(&mut self, rhs: $vector<T>) {
+                $(self.$field -= rhs.$field;)+
+            }
+        }
+
+        impl<T: Coordinate> std::ops::Sub for $point<T> {
+            type Output = $vector<T>;
+
+            fn sub(self, rhs: Self) -> Self::Output {
+                $vector { $($field: self.$field - rhs.$field),+ }
+            }
+        }
+
+        impl<T: Coordinate> std::ops::Add for $vector<T> {
+            type Output = $vector<T>;
+
+            fn add(self, rhs: Self) -> Self::Output {
+                $vector { $($field: self.$field + rhs.$field),+ }
+            }
+        }
+
+        impl<T: Coordinate> std::ops::AddAssign for $vector<T> {
+            fn add_assign(&mut self, rhs: Self) {
+                $(self.$field += rhs.$field;)+
+            }
+        }
+
+        impl<T: Coordinate> std::ops::Sub for $vector<T> {
+            type Output = $vector<T>;
+
+            fn sub(self, rhs: Self) -> Self::Output {
+                $vector { $($field: self.$field - rhs.$field),+ }
+            }
+        }
+
+        impl<T: Coordinate> std::ops::SubAssign for $vector<T> {
+            fn sub_assign(&mut self, rhs: Self) {
+                $(self.$field -= rhs.$field;)+
+            }
+        }
+
+        impl<T: Coordinate> std::ops::Neg for $vector<T> {
+            type Output = $vector<T>;
+
+            fn neg(self) -> Self::Output {
+                $vector { $($field: -self.$field),+ }
+            }
+        }
+
+        impl<T: Coordinate> std::ops::Mul<T> for $vector<T> {
+            type Output = $vector<T>;
+
+            fn mul(self, rhs: T) -> Self::Output {
+                $vector { $($field: self.$field * rhs),+ }
+            }
+        }
+
+        impl<T: Coordinate> $vector<T> {
+            /// The sum of the absolute values of the components: how many orthogonal steps this
+            /// vector takes.
+            pub fn manhattan_length(self) -> T {
+                let mut length = T::ZERO;
+                $(length += self.$field.abs();)+
+                length
+            }
+
+            /// The largest absolute value of the components: how many steps this vector takes when
+            /// diagonal steps are allowed.
+            pub fn chebyshev_length(self) -> T {
+                let mut length = T::ZERO;
+                $(length = std::cmp::max(length, self.$field.abs());)+
+                length
+            }
+
+            /// The vector with each component replaced by its sign: a single step (possibly
+            /// diagonal) toward where this vector points.
+            pub fn signum(self) -> Self {
+                $vector { $($field: self.$field.signum()),+ }
+            }
+        }
+
+        impl<T: Coordinate> $point<T> {
+            /// The number of orthogonal steps between two points.
+            pub fn manhattan_distance(self, other: Self) -> T {
+                (self - other).manhattan_length()
+            }
+
+            /// The number of steps between two points when diagonal steps are allowed.
+            pub fn chebyshev_distance(self, other: Self) -> T {
+                (self - other).chebyshev_length()
+            }
+        }
+
+        impl<T: std::str::FromStr> std::str::FromStr for $point<T> {
+            type Err = &'static str;
+
+            /// Parse comma-separated components, like `2,-3`.
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let mut parts = s.trim().split(',').map(str::trim).fuse();
+                $(
+                    let $field = parts
+                        .next()
+                        .ok_or(concat!("Missing ", stringify!($field)))?
+                        .parse()
+                        .map_err(|_| concat!("Failed to parse ", stringify!($field)))?;
+                )+
+                if parts.next().is_some() {
+                    return Err("Too many components");
+                }
+                Ok($point { $($field),+ })
+            }
+        }
+    };
+}
+
+mod bounds;
+mod coordinate;
+mod direction;
+mod point;
+mod point3;
+
+pub use bounds::{Bounds, Bounds3};
+pub use coordinate::Coordinate;
+pub use direction::{Direction, Turn};
+pub use point::{Point, Vector};
+pub use point3::{Point3, Vector3};
@@ -0,0 +1,119 @@
+use crate::{Coordinate, Direction};
+
+/// A position on a grid.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash, PartialOrd, Ord)]
+pub struct Point<T = i32> {
+    pub x: T,
+    pub y: T,
+}
+
+/// The offset between two [`Point`]s.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+pub struct Vector<T = i32> {
+    pub x: T,
+    pub y: T,
+}
+
+impl_ops!(Point, Vector, x, y);
+
+impl<T> Point<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T: Coordinate> Point<T> {
+    pub const ORIGIN: Self = Self {
+        x: T::ZERO,
+        y: T::ZERO,
+    };
+
+    /// The point one step away in `direction`.
+    pub fn step(self, direction: Direction) -> Self {
+        self + direction.vector()
+    }
+
+    /// The four points that share an edge with this one, clockwise from north.
+    pub fn neighbours(self) -> impl Iterator<Item = Self> {
+        Direction::CARDINAL
+            .into_iter()
+            .map(move |direction| self.step(direction))
+    }
+
+    /// The eight points that share an edge or a corner with this one, clockwise from north.
+    pub fn neighbours_with_diagonals(self) -> impl Iterator<Item = Self> {
+        Direction::ALL
+            .into_iter()
+            .map(move |direction| self.step(direction))
+    }
+}
+
+impl<T> Vector<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T: Coordinate> Vector<T> {
+    pub const ZERO: Self = Self {
+        x: T::ZERO,
+        y: T::ZERO,
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let a = Point::new(2, 3);
+        let b = Point::new(-1, 7);
+        assert_eq!(b - a, Vector::new(-3, 4));
+        assert_eq!(a + (b - a), b);
+        assert_eq!(a - Vector::new(1, 1), Point::new(1, 2));
+        assert_eq!(Vector::new(1, -2) * 3, Vector::new(3, -6));
+        assert_eq!(-Vector::new(1, -2), Vector::new(-1, 2));
+        assert_eq!(Vector::new(-5, 0).signum(), Vector::new(-1, 0));
+
+        let mut c = a;
+        c += Vector::new(10, 10);
+        assert_eq!(c, Point::new(12, 13));
+    }
+
+    #[test]
+    fn test_distances() {
+        let a = Point::new(2, 3);
+        let b = Point::new(-1, 7);
+        assert_eq!(a.manhattan_distance(b), 7);
+        assert_eq!(a.chebyshev_distance(b), 4);
+        assert_eq!(b.manhattan_distance(a), 7);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let point = Point::<isize>::new(0, 0);
+        assert_eq!(
+            point.neighbours().collect::<Vec<_>>(),
+            vec![
+                Point::new(0, -1),
+                Point::new(1, 0),
+                Point::new(0, 1),
+                Point::new(-1, 0)
+            ]
+        );
+        assert_eq!(point.neighbours_with_diagonals().count(), 8);
+        assert!(point
+            .neighbours_with_diagonals()
+            .all(|neighbour| neighbour.chebyshev_distance(point) == 1));
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("498,4".parse(), Ok(Point::new(498, 4)));
+        assert_eq!("-2, 15".parse(), Ok(Point::new(-2, 15)));
+        assert!("1".parse::<Point>().is_err());
+        assert!("1,2,3".parse::<Point>().is_err());
+        assert!("x,2".parse::<Point>().is_err());
+    }
+}
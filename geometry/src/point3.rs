@@ -0,0 +1,110 @@
+use crate::Coordinate;
+
+/// A position in space.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash, PartialOrd, Ord)]
+pub struct Point3<T = i32> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+/// The offset between two [`Point3`]s.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+pub struct Vector3<T = i32> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl_ops!(Point3, Vector3, x, y, z);
+
+impl<T> Point3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl<T: Coordinate> Point3<T> {
+    pub const ORIGIN: Self = Self {
+        x: T::ZERO,
+        y: T::ZERO,
+        z: T::ZERO,
+    };
+
+    /// The six points that share a face with this one.
+    pub fn neighbours(self) -> impl Iterator<Item = Self> {
+        let (zero, one) = (T::ZERO, T::ONE);
+        [
+            Vector3::new(-one, zero, zero),
+            Vector3::new(one, zero, zero),
+            Vector3::new(zero, -one, zero),
+            Vector3::new(zero, one, zero),
+            Vector3::new(zero, zero, -one),
+            Vector3::new(zero, zero, one),
+        ]
+        .into_iter()
+        .map(move |vector| self + vector)
+    }
+
+    /// The twenty-six points that share a face, an edge, or a corner with this one.
+    pub fn neighbours_with_diagonals(self) -> impl Iterator<Item = Self> {
+        let steps = [-T::ONE, T::ZERO, T::ONE];
+        steps
+            .into_iter()
+            .flat_map(move |x| {
+                steps
+                    .into_iter()
+                    .flat_map(move |y| steps.into_iter().map(move |z| Vector3::new(x, y, z)))
+            })
+            .filter(|vector| *vector != Vector3::ZERO)
+            .map(move |vector| self + vector)
+    }
+}
+
+impl<T> Vector3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl<T: Coordinate> Vector3<T> {
+    pub const ZERO: Self = Self {
+        x: T::ZERO,
+        y: T::ZERO,
+        z: T::ZERO,
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let a = Point3::new(1, 2, 3);
+        let b = Point3::new(3, 0, -1);
+        assert_eq!(b - a, Vector3::new(2, -2, -4));
+        assert_eq!(a + (b - a), b);
+        assert_eq!(a.manhattan_distance(b), 8);
+        assert_eq!(a.chebyshev_distance(b), 4);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let point = Point3::new(1, 1, 1);
+        assert_eq!(point.neighbours().count(), 6);
+        assert!(point
+            .neighbours()
+            .all(|neighbour| neighbour.manhattan_distance(point) == 1));
+        assert_eq!(point.neighbours_with_diagonals().count(), 26);
+        assert!(point
+            .neighbours_with_diagonals()
+            .all(|neighbour| neighbour.chebyshev_distance(point) == 1));
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("2,2,5".parse(), Ok(Point3::new(2, 2, 5)));
+        assert!("2,2".parse::<Point3>().is_err());
+    }
+}
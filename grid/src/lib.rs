@@ -0,0 +1,296 @@
+//! A dense, rectangular grid of cells, for the days whose puzzle input is a picture.
+
+use aoc_core::ParseError;
+use geometry::{Direction, Point};
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+/// A rectangular grid, stored a row at a time. `(0, 0)` is the top left cell.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A grid where every cell starts as `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// A grid where each cell starts as whatever `f` returns for its point.
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(Point) -> T) -> Self {
+        let mut cells = Vec::with_capacity(width * height);
+        for y in 0..height {
+            for x in 0..width {
+                cells.push(f(Point::new(x as i32, y as i32)));
+            }
+        }
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parse a picture with one row per line, mapping each character to a cell with `f`. Every
+    /// line must be the same length. Errors from `f` point at the character it was given.
+    pub fn parse_from_chars<E: Into<ParseError>>(
+        input: &str,
+        mut f: impl FnMut(Point, char) -> Result<T, E>,
+    ) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+        for (y, line) in input.lines().enumerate() {
+            let mut line_width = 0;
+            for (x, (index, c)) in line.char_indices().enumerate() {
+                let cell = f(Point::new(x as i32, y as i32), c)
+                    .map_err(|err| err.into().within(&line[index..index + c.len_utf8()]))?;
+                cells.push(cell);
+                line_width += 1;
+            }
+            let width = *width.get_or_insert(line_width);
+            if width != line_width {
+                return Err(ParseError::expected(
+                    format!("a row {width} characters wide like the first"),
+                    line,
+                ));
+            }
+            height += 1;
+        }
+        Ok(Self {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        0 <= point.x
+            && (point.x as usize) < self.width
+            && 0 <= point.y
+            && (point.y as usize) < self.height
+    }
+
+    fn index_of(&self, point: Point) -> Option<usize> {
+        self.contains(point)
+            .then(|| point.y as usize * self.width + point.x as usize)
+    }
+
+    /// The cell at `point`, or `None` if it's outside the grid.
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.index_of(point).map(|index| &self.cells[index])
+    }
+
+    /// The cell at `point`, or `None` if it's outside the grid.
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.index_of(point).map(|index| &mut self.cells[index])
+    }
+
+    /// Every point in the grid, a row at a time.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point::new(x as i32, y as i32)))
+    }
+
+    /// Every cell and its point, a row at a time.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    /// The point of the first cell, a row at a time, that matches `predicate`.
+    pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Point> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(point, _)| point)
+    }
+
+    /// Every row, from the top.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` panics on zero, and an empty grid has no rows anyway.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// The cells of row `y`, from the left. Empty if `y` is outside the grid.
+    pub fn row(&self, y: usize) -> impl Iterator<Item = &T> {
+        let row = if y < self.height {
+            &self.cells[y * self.width..(y + 1) * self.width]
+        } else {
+            &[]
+        };
+        row.iter()
+    }
+
+    /// The cells of column `x`, from the top. Empty if `x` is outside the grid.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        let height = if x < self.width { self.height } else { 0 };
+        self.cells
+            .iter()
+            .skip(x)
+            .step_by(self.width.max(1))
+            .take(height)
+    }
+
+    /// The cells you pass looking from `start` in `direction`, nearest first, until the edge of
+    /// the grid. `start` itself isn't included.
+    pub fn ray(&self, start: Point, direction: Direction) -> impl Iterator<Item = (Point, &T)> {
+        std::iter::successors(Some(start), move |point| Some(point.step(direction)))
+            .skip(1)
+            .map_while(|point| self.get(point).map(|cell| (point, cell)))
+    }
+
+    /// The cells that share an edge with `point`, clockwise from north.
+    pub fn neighbours(&self, point: Point) -> impl Iterator<Item = (Point, &T)> {
+        point
+            .neighbours()
+            .filter_map(|point| self.get(point).map(|cell| (point, cell)))
+    }
+
+    /// The cells that share an edge or a corner with `point`, clockwise from north.
+    pub fn neighbours_with_diagonals(&self, point: Point) -> impl Iterator<Item = (Point, &T)> {
+        point
+            .neighbours_with_diagonals()
+            .filter_map(|point| self.get(point).map(|cell| (point, cell)))
+    }
+
+    /// A grid of the same size with `f` applied to every cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &Self::Output {
+        self.get(point)
+            .unwrap_or_else(|| panic!("{point:?} is outside the grid"))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut Self::Output {
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{point:?} is outside the grid"))
+    }
+}
+
+/// Draws the grid a row per line, each cell drawn with its own `Display`.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                cell.fmt(f)?;
+            }
+            f.write_str("\n")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(input: &str) -> Grid<u8> {
+        Grid::parse_from_chars(input, |_, c| {
+            c.to_digit(10).map(|d| d as u8).ok_or("expected a digit")
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_parse_from_chars() {
+        let grid = digits("123\n456\n");
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid.get(Point::new(0, 1)), Some(&4));
+        assert_eq!(grid.get(Point::new(3, 1)), None);
+        assert_eq!(grid.get(Point::new(-1, 0)), None);
+        assert_eq!(grid[Point::new(2, 0)], 3);
+
+        assert!(Grid::parse_from_chars("12\n3\n", |_, c| Ok::<_, ParseError>(c)).is_err());
+        let input = "12\n3x\n";
+        let err = Grid::parse_from_chars(input, |_, c| c.to_digit(10).ok_or("expected a digit"))
+            .unwrap_err()
+            .locate(input);
+        let location = err.location().unwrap();
+        assert_eq!((location.line(), location.column()), (2, 2));
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = digits("123\n456\n");
+        assert_eq!(
+            grid.rows().collect::<Vec<_>>(),
+            vec![&[1, 2, 3], &[4, 5, 6]]
+        );
+        assert_eq!(grid.row(1).copied().collect::<Vec<_>>(), vec![4, 5, 6]);
+        assert_eq!(grid.row(2).count(), 0);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), vec![2, 5]);
+        assert_eq!(grid.column(3).count(), 0);
+    }
+
+    #[test]
+    fn test_ray() {
+        let grid = digits("123\n456\n789\n");
+        let ray = |x, y, direction| {
+            grid.ray(Point::new(x, y), direction)
+                .map(|(_, cell)| *cell)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(ray(0, 0, Direction::East), vec![2, 3]);
+        assert_eq!(ray(1, 2, Direction::North), vec![5, 2]);
+        assert_eq!(ray(2, 2, Direction::NorthWest), vec![5, 1]);
+        assert_eq!(ray(2, 2, Direction::South), vec![]);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = digits("123\n456\n789\n");
+        let neighbours = |x, y| {
+            grid.neighbours(Point::new(x, y))
+                .map(|(_, cell)| *cell)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(neighbours(1, 1), vec![2, 6, 8, 4]);
+        assert_eq!(neighbours(0, 0), vec![2, 4]);
+        assert_eq!(grid.neighbours_with_diagonals(Point::new(0, 0)).count(), 3);
+    }
+
+    #[test]
+    fn test_display() {
+        let mut grid = Grid::new(3, 2, '.');
+        grid[Point::new(1, 1)] = '#';
+        *grid.get_mut(Point::new(0, 0)).unwrap() = '#';
+        assert_eq!(grid.to_string(), "#..\n.#.\n");
+        assert_eq!(grid.position(|c| *c == '#'), Some(Point::new(0, 0)));
+        assert_eq!(
+            grid.map(|c| *c == '#').iter().filter(|(_, c)| **c).count(),
+            2
+        );
+    }
+}
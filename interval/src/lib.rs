@@ -0,0 +1,213 @@
+//! Closed intervals of integers, like the sections an elf cleans or the stretch of a row a sensor
+//! can see, sets of them, and trees for finding which of many intervals hold a value.
+
+mod set;
+mod tree;
+
+pub use set::IntervalSet;
+use std::{
+    fmt::Debug,
+    hash::Hash,
+    ops::{Add, Sub},
+};
+pub use tree::IntervalTree;
+
+/// An integer type that intervals can be made of.
+pub trait Integer:
+    Copy + Debug + Eq + Ord + Hash + Add<Output = Self> + Sub<Output = Self>
+{
+    const ONE: Self;
+    const MIN: Self;
+    const MAX: Self;
+}
+
+macro_rules! impl_integer {
+    ($($ty:ty),*) => {
+        $(
+            impl Integer for $ty {
+                const ONE: Self = 1;
+                const MIN: Self = <$ty>::MIN;
+                const MAX: Self = <$ty>::MAX;
+            }
+        )*
+    };
+}
+
+impl_integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// Every integer from `start` to `end`, both included. An interval always holds at least one.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Interval<T> {
+    start: T,
+    end: T,
+}
+
+impl<T: Integer> Interval<T> {
+    /// # Panics
+    ///
+    /// If `start` is after `end`.
+    pub fn new(start: T, end: T) -> Self {
+        Self::checked(start, end)
+            .unwrap_or_else(|| panic!("an interval can't start at {start:?} after its end {end:?}"))
+    }
+
+    /// The interval from `start` to `end`, or None if `start` is after `end`.
+    pub fn checked(start: T, end: T) -> Option<Self> {
+        (start <= end).then_some(Self { start, end })
+    }
+
+    /// The interval holding only `value`.
+    pub fn single(value: T) -> Self {
+        Self {
+            start: value,
+            end: value,
+        }
+    }
+
+    pub fn start(&self) -> T {
+        self.start
+    }
+
+    pub fn end(&self) -> T {
+        self.end
+    }
+
+    /// How many integers the interval holds. This overflows for an interval of every value of `T`.
+    pub fn len(&self) -> T {
+        self.end - self.start + T::ONE
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.start <= value && value <= self.end
+    }
+
+    /// Whether every value in `other` is also in `self`.
+    pub fn contains_interval(&self, other: &Self) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    /// Whether `self` and `other` hold at least one value in common.
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    /// Whether one interval ends right before the other starts, with no gap and no overlap.
+    pub fn is_adjacent(&self, other: &Self) -> bool {
+        let touches = |before: &Self, after: &Self| {
+            before.end != T::MAX && before.end + T::ONE == after.start
+        };
+        touches(self, other) || touches(other, self)
+    }
+
+    /// The values in both `self` and `other`, if there are any.
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        Self::checked(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    /// The values in either `self` or `other`, if they overlap or are adjacent, so that the result
+    /// is a single interval.
+    pub fn union(&self, other: &Self) -> Option<Self> {
+        (self.overlaps(other) || self.is_adjacent(other)).then(|| Self {
+            start: self.start.min(other.start),
+            end: self.end.max(other.end),
+        })
+    }
+
+    /// The values in `self` but not in `other`: whatever's left before `other`, and whatever's
+    /// left after it.
+    pub fn difference(&self, other: &Self) -> [Option<Self>; 2] {
+        if !self.overlaps(other) {
+            return [Some(*self), None];
+        }
+        // `other` starts after `self` does, so it can't start at the very smallest value; likewise
+        // for the end.
+        let before = (self.start < other.start).then(|| Self {
+            start: self.start,
+            end: other.start - T::ONE,
+        });
+        let after = (other.end < self.end).then(|| Self {
+            start: other.end + T::ONE,
+            end: self.end,
+        });
+        [before, after]
+    }
+
+    /// Every value in the interval, in order.
+    pub fn iter(&self) -> impl Iterator<Item = T> {
+        let end = self.end;
+        std::iter::successors(Some(self.start), move |&value| {
+            (value < end).then(|| value + T::ONE)
+        })
+    }
+}
+
+/// Written like a range pattern, `2..=4`.
+impl<T: Debug> Debug for Interval<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}..={:?}", self.start, self.end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn interval(start: i32, end: i32) -> Interval<i32> {
+        Interval::new(start, end)
+    }
+
+    #[test]
+    fn test_new() {
+        assert_eq!(Interval::checked(3u64, 2), None);
+        assert_eq!(interval(2, 4).len(), 3);
+        assert_eq!(Interval::single(7u8).len(), 1);
+        assert_eq!(interval(-1, 2).iter().collect::<Vec<_>>(), [-1, 0, 1, 2]);
+        assert_eq!(format!("{:?}", interval(2, 4)), "2..=4");
+    }
+
+    #[test]
+    fn test_containment() {
+        assert!(interval(2, 8).contains_interval(&interval(3, 7)));
+        assert!(!interval(3, 7).contains_interval(&interval(2, 8)));
+        assert!(interval(5, 7).overlaps(&interval(7, 9)));
+        assert!(interval(2, 8).overlaps(&interval(3, 7)));
+        assert!(!interval(2, 4).overlaps(&interval(6, 8)));
+        assert!(interval(2, 4).is_adjacent(&interval(5, 8)));
+        assert!(interval(5, 8).is_adjacent(&interval(2, 4)));
+        assert!(!interval(2, 5).is_adjacent(&interval(5, 8)));
+        assert!(!Interval::new(250u8, 255).is_adjacent(&Interval::new(0, 3)));
+    }
+
+    #[test]
+    fn test_intersection_and_union() {
+        assert_eq!(
+            interval(2, 6).intersection(&interval(4, 8)),
+            Some(interval(4, 6))
+        );
+        assert_eq!(interval(2, 3).intersection(&interval(4, 8)), None);
+        assert_eq!(interval(2, 6).union(&interval(4, 8)), Some(interval(2, 8)));
+        assert_eq!(interval(2, 3).union(&interval(4, 8)), Some(interval(2, 8)));
+        assert_eq!(interval(2, 3).union(&interval(5, 8)), None);
+    }
+
+    #[test]
+    fn test_difference() {
+        assert_eq!(
+            interval(2, 8).difference(&interval(4, 5)),
+            [Some(interval(2, 3)), Some(interval(6, 8))]
+        );
+        assert_eq!(
+            interval(2, 8).difference(&interval(0, 5)),
+            [None, Some(interval(6, 8))]
+        );
+        assert_eq!(interval(2, 8).difference(&interval(0, 9)), [None, None]);
+        assert_eq!(
+            interval(2, 8).difference(&interval(9, 9)),
+            [Some(interval(2, 8)), None]
+        );
+        assert_eq!(
+            Interval::new(0u32, 3).difference(&Interval::new(0, 1)),
+            [None, Some(Interval::new(2, 3))]
+        );
+    }
+}
@@ -0,0 +1,190 @@
+use crate::{Integer, Interval};
+
+/// A set of integers, kept as the fewest intervals that hold them: sorted, with no two
+/// overlapping or adjacent.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
+pub struct IntervalSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T: Integer> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self {
+            intervals: Vec::new(),
+        }
+    }
+
+    /// Add every value in `interval`, merging it with any interval it overlaps or touches.
+    pub fn insert(&mut self, interval: Interval<T>) {
+        // Everything before `first` ends too early to merge, and everything from `last` on starts
+        // too late.
+        let first = self.intervals.partition_point(|existing| {
+            existing.end < interval.start && !existing.is_adjacent(&interval)
+        });
+        let last = self.intervals.partition_point(|existing| {
+            existing.start <= interval.end || existing.is_adjacent(&interval)
+        });
+        let merged = self.intervals[first..last]
+            .iter()
+            .fold(interval, |merged, existing| {
+                merged.union(existing).expect("they overlap or touch")
+            });
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    /// Take out every value in `interval`.
+    pub fn remove(&mut self, interval: Interval<T>) {
+        let first = self
+            .intervals
+            .partition_point(|existing| existing.end < interval.start);
+        let last = self
+            .intervals
+            .partition_point(|existing| existing.start <= interval.end);
+        let left = self.intervals[first..last]
+            .iter()
+            .flat_map(|existing| existing.difference(&interval))
+            .flatten()
+            .collect::<Vec<_>>();
+        self.intervals.splice(first..last, left);
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.containing(&Interval::single(value)).is_some()
+    }
+
+    /// Whether every value in `interval` is in the set.
+    pub fn contains_interval(&self, interval: &Interval<T>) -> bool {
+        self.containing(interval).is_some()
+    }
+
+    /// The one interval of the set that `interval` falls within, if there is one.
+    fn containing(&self, interval: &Interval<T>) -> Option<&Interval<T>> {
+        let index = self
+            .intervals
+            .partition_point(|existing| existing.end < interval.start);
+        self.intervals
+            .get(index)
+            .filter(|existing| existing.contains_interval(interval))
+    }
+
+    /// The intervals, in order.
+    pub fn as_slice(&self) -> &[Interval<T>] {
+        &self.intervals
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, Interval<T>> {
+        self.intervals.iter()
+    }
+
+    /// How many separate intervals there are.
+    pub fn len(&self) -> usize {
+        self.intervals.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// From the smallest value in the set to the biggest.
+    pub fn span(&self) -> Option<Interval<T>> {
+        let (first, last) = (self.intervals.first()?, self.intervals.last()?);
+        Some(Interval::new(first.start, last.end))
+    }
+
+    /// The gaps between the intervals, in order.
+    pub fn gaps(&self) -> impl Iterator<Item = Interval<T>> + '_ {
+        self.intervals
+            .windows(2)
+            .map(|pair| Interval::new(pair[0].end + T::ONE, pair[1].start - T::ONE))
+    }
+
+    /// How many values the set holds.
+    pub fn count(&self) -> T
+    where
+        T: std::iter::Sum,
+    {
+        self.intervals.iter().map(Interval::len).sum()
+    }
+}
+
+impl<T: Integer> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        for interval in iter {
+            set.insert(interval);
+        }
+        set
+    }
+}
+
+impl<'a, T> IntoIterator for &'a IntervalSet<T> {
+    type Item = &'a Interval<T>;
+    type IntoIter = std::slice::Iter<'a, Interval<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.intervals.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(intervals: &[(i32, i32)]) -> IntervalSet<i32> {
+        intervals
+            .iter()
+            .map(|&(start, end)| Interval::new(start, end))
+            .collect()
+    }
+
+    fn intervals(set: &IntervalSet<i32>) -> Vec<(i32, i32)> {
+        set.iter()
+            .map(|interval| (interval.start(), interval.end()))
+            .collect()
+    }
+
+    #[test]
+    fn test_insert() {
+        assert_eq!(intervals(&set(&[(0, 2), (4, 6), (2, 4)])), [(0, 6)]);
+        assert_eq!(intervals(&set(&[(0, 2), (3, 4)])), [(0, 4)]);
+        assert_eq!(
+            intervals(&set(&[(10, 12), (0, 2), (5, 6)])),
+            [(0, 2), (5, 6), (10, 12)]
+        );
+        assert_eq!(
+            intervals(&set(&[(0, 2), (5, 6), (10, 12), (1, 10)])),
+            [(0, 12)]
+        );
+        assert_eq!(
+            intervals(&set(&[(0, 2), (8, 9), (4, 4)])),
+            [(0, 2), (4, 4), (8, 9)]
+        );
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut set = set(&[(0, 10), (20, 30)]);
+        set.remove(Interval::new(5, 22));
+        assert_eq!(intervals(&set), [(0, 4), (23, 30)]);
+        set.remove(Interval::new(0, 4));
+        assert_eq!(intervals(&set), [(23, 30)]);
+        set.remove(Interval::new(25, 26));
+        assert_eq!(intervals(&set), [(23, 24), (27, 30)]);
+    }
+
+    #[test]
+    fn test_queries() {
+        let set = set(&[(0, 2), (5, 6), (10, 12)]);
+        assert!(set.contains(5));
+        assert!(!set.contains(3));
+        assert!(set.contains_interval(&Interval::new(10, 12)));
+        assert!(!set.contains_interval(&Interval::new(2, 5)));
+        assert_eq!(set.span(), Some(Interval::new(0, 12)));
+        assert_eq!(
+            set.gaps().collect::<Vec<_>>(),
+            [Interval::new(3, 4), Interval::new(7, 9)]
+        );
+        assert_eq!(set.count(), 3 + 2 + 3);
+        assert_eq!(IntervalSet::<u8>::new().span(), None);
+    }
+}
@@ -0,0 +1,143 @@
+use crate::{Integer, Interval};
+
+/// Intervals with a value attached to each, built once and then asked which intervals hold a
+/// value or overlap an interval, in time proportional to the depth of the tree plus the number of
+/// answers.
+///
+/// The intervals are sorted by where they start and laid out as a balanced binary search tree,
+/// with each node's subtree stretched over a slice of them. Each node also knows the furthest end
+/// in its subtree, so that subtrees ending too early are skipped whole.
+#[derive(Debug, Clone)]
+pub struct IntervalTree<T, V> {
+    entries: Vec<(Interval<T>, V)>,
+    /// The furthest end in the subtree rooted at each entry.
+    max_end: Vec<T>,
+}
+
+impl<T: Integer, V> IntervalTree<T, V> {
+    pub fn new(entries: impl IntoIterator<Item = (Interval<T>, V)>) -> Self {
+        let mut entries = entries.into_iter().collect::<Vec<_>>();
+        entries.sort_by_key(|(interval, _)| *interval);
+        let mut max_end = entries
+            .iter()
+            .map(|(interval, _)| interval.end())
+            .collect::<Vec<_>>();
+        fill_max_end(&mut max_end, 0, entries.len());
+        Self { entries, max_end }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Every entry whose interval holds `value`, ordered by where the intervals start.
+    pub fn containing(&self, value: T) -> Vec<&(Interval<T>, V)> {
+        self.overlapping(&Interval::single(value))
+    }
+
+    /// Every entry whose interval overlaps `interval`, ordered by where the intervals start.
+    pub fn overlapping(&self, interval: &Interval<T>) -> Vec<&(Interval<T>, V)> {
+        let mut found = Vec::new();
+        self.search(0, self.entries.len(), interval, &mut found);
+        found
+    }
+
+    fn search<'a>(
+        &'a self,
+        low: usize,
+        high: usize,
+        interval: &Interval<T>,
+        found: &mut Vec<&'a (Interval<T>, V)>,
+    ) {
+        if low >= high {
+            return;
+        }
+        let middle = low + (high - low) / 2;
+        // Nothing down here reaches `interval`.
+        if self.max_end[middle] < interval.start() {
+            return;
+        }
+        self.search(low, middle, interval, found);
+        let entry = &self.entries[middle];
+        // Everything to the right starts at least as late as this one, so if this one starts too
+        // late, so do they.
+        if interval.end() < entry.0.start() {
+            return;
+        }
+        if entry.0.overlaps(interval) {
+            found.push(entry);
+        }
+        self.search(middle + 1, high, interval, found);
+    }
+}
+
+impl<T: Integer, V> FromIterator<(Interval<T>, V)> for IntervalTree<T, V> {
+    fn from_iter<I: IntoIterator<Item = (Interval<T>, V)>>(iter: I) -> Self {
+        Self::new(iter)
+    }
+}
+
+/// Turn each end in `max_end[low..high]` into the furthest end of the subtree rooted there, and
+/// return the furthest of them all.
+fn fill_max_end<T: Integer>(max_end: &mut [T], low: usize, high: usize) -> Option<T> {
+    if low >= high {
+        return None;
+    }
+    let middle = low + (high - low) / 2;
+    let left = fill_max_end(max_end, low, middle);
+    let right = fill_max_end(max_end, middle + 1, high);
+    let furthest = [left, right]
+        .into_iter()
+        .flatten()
+        .fold(max_end[middle], T::max);
+    max_end[middle] = furthest;
+    Some(furthest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tree() {
+        let intervals = [
+            (5, 20),
+            (10, 30),
+            (12, 15),
+            (15, 20),
+            (17, 19),
+            (30, 40),
+            (1, 3),
+        ];
+        let tree = intervals
+            .iter()
+            .enumerate()
+            .map(|(index, &(start, end))| (Interval::new(start, end), index))
+            .collect::<IntervalTree<i64, usize>>();
+        for value in 0..45 {
+            let mut expected = intervals
+                .iter()
+                .enumerate()
+                .filter(|(_, &(start, end))| start <= value && value <= end)
+                .map(|(index, _)| index)
+                .collect::<Vec<_>>();
+            let mut found = tree
+                .containing(value)
+                .into_iter()
+                .map(|&(_, index)| index)
+                .collect::<Vec<_>>();
+            expected.sort();
+            found.sort();
+            assert_eq!(found, expected, "at {value}");
+        }
+
+        let found = tree.overlapping(&Interval::new(21, 29));
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].1, 1);
+        assert!(IntervalTree::<u8, ()>::new([]).containing(3).is_empty());
+    }
+}
@@ -0,0 +1,135 @@
+//! The WebAssembly module behind `aoc2022-vis.html`: it parses the input typed into the page and
+//! runs whichever day's [`Visualize`] was picked, drawing each tick through [`Canvas`].
+
+use aoc_core::ParseError;
+use vis::{log, Canvas, Visualize};
+
+/// A puzzle input being filled in by the page, and then the animation of it.
+pub struct Host {
+    day: u8,
+    input: Vec<u8>,
+    visualization: Option<Box<dyn Visualize>>,
+}
+
+impl Host {
+    /// The days with an animation.
+    pub const DAYS: [u8; 4] = [6, 9, 12, 14];
+
+    pub fn new(day: u8, input_size: usize) -> Option<Self> {
+        Self::DAYS.contains(&day).then(|| Self {
+            day,
+            input: Vec::with_capacity(input_size),
+            visualization: None,
+        })
+    }
+
+    /// Start animating `input`.
+    pub fn start(&mut self, input: &str) -> Result<(), ParseError> {
+        let visualization: Box<dyn Visualize> = match self.day {
+            6 => Box::new(day_06::vis::Visualization::new(input.trim())),
+            9 => Box::new(day_09::vis::Visualization::new(day_09::Command::parse(
+                input,
+            )?)),
+            12 => Box::new(day_12::vis::Visualization::new(day_12::Heightmap::parse(
+                input,
+            )?)),
+            14 => Box::new(day_14::vis::Visualization::new(&day_14::parse_input(
+                input,
+            )?)),
+            day => unreachable!("day {day} has no visualization"),
+        };
+        self.visualization = Some(visualization);
+        Ok(())
+    }
+
+    /// Move the animation along and draw it. True once it's finished, or if it never started.
+    pub fn tick(&mut self, every_step: bool) -> bool {
+        let Some(visualization) = &mut self.visualization else {
+            return true;
+        };
+        let done = visualization.tick(every_step);
+        visualization.draw(&Canvas);
+        done
+    }
+}
+
+mod sys {
+    use super::*;
+
+    /// A host for `day`, with room for `input_size` bytes of input, or null if the day has no
+    /// visualization.
+    #[no_mangle]
+    extern "C" fn visualization_create(day: u32, input_size: u32) -> *mut Host {
+        let Some(host) = u8::try_from(day)
+            .ok()
+            .and_then(|day| Host::new(day, input_size as usize))
+        else {
+            log(format!("day {day} has no visualization"));
+            return std::ptr::null_mut();
+        };
+        Box::into_raw(Box::new(host))
+    }
+
+    /// Where the page should write the input.
+    #[no_mangle]
+    extern "C" fn visualization_input_address(host: &mut Host) -> *mut u8 {
+        host.input.as_mut_ptr()
+    }
+
+    /// Called once the page has written `size` bytes of input. False if it couldn't be parsed.
+    #[no_mangle]
+    extern "C" fn visualization_input_set(host: &mut Host, size: u32) -> bool {
+        let size = (size as usize).min(host.input.capacity());
+        // SAFETY: the page has just written `size` bytes at `visualization_input_address`, and the
+        // capacity was reserved for them.
+        unsafe { host.input.set_len(size) }
+        let input = std::mem::take(&mut host.input);
+        let input = match String::from_utf8(input) {
+            Ok(input) => input,
+            Err(err) => {
+                log(format!("the input isn't UTF-8: {err}"));
+                return false;
+            }
+        };
+        match host.start(&input) {
+            Ok(()) => true,
+            Err(err) => {
+                log(format!(
+                    "failed to parse the input for day {}: {}",
+                    host.day,
+                    err.locate(&input)
+                ));
+                false
+            }
+        }
+    }
+
+    #[no_mangle]
+    extern "C" fn visualization_tick(host: &mut Host, every_step: bool) -> bool {
+        host.tick(every_step)
+    }
+
+    #[no_mangle]
+    extern "C" fn visualization_free(host: Box<Host>) {
+        std::mem::drop(host)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_days() {
+        assert!(Host::new(7, 0).is_none());
+        for day in Host::DAYS {
+            // Nothing to animate until the input is in.
+            assert!(Host::new(day, 0).unwrap().tick(false));
+        }
+
+        let mut host = Host::new(9, 0).unwrap();
+        host.start("R 4\nU 4").unwrap();
+        assert!(!host.tick(false));
+        assert!(host.tick(false));
+    }
+}
@@ -0,0 +1,158 @@
+//! Animations of puzzle solutions, drawn in a browser by the page that loads the `vis-wasm`
+//! module. Each day that can be animated has a `vis` module with a type implementing
+//! [`Visualize`].
+//!
+//! The page shows a square of [`Canvas::SIZE`] boxes, each of which can be filled with a color and
+//! have a character drawn in it. A [`Viewport`] picks which part of a bigger puzzle is on screen.
+
+use geometry::{Bounds, Point};
+
+/// A puzzle being solved one step at a time.
+pub trait Visualize {
+    /// Move the solution along. With `every_step` it moves by the smallest step there is; without,
+    /// it skips ahead to the next state worth looking at. True once the solution is finished.
+    fn tick(&mut self, every_step: bool) -> bool;
+
+    /// Draw the current state, replacing whatever was drawn before.
+    fn draw(&self, canvas: &Canvas);
+}
+
+/// The screen, as a square of boxes. Each call draws straight to the page, using the most recently
+/// set color.
+pub struct Canvas;
+
+impl Canvas {
+    /// How many boxes the screen is across, and down.
+    pub const SIZE: usize = 64;
+
+    pub fn set_fill_color_rgb(&self, r: u8, g: u8, b: u8) {
+        unsafe { sys::fill_color_rgb(r, g, b) }
+    }
+
+    pub fn gray(&self) {
+        self.set_fill_color_rgb(100, 100, 100);
+    }
+
+    pub fn dark_red(&self) {
+        self.set_fill_color_rgb(80, 0, 0)
+    }
+
+    pub fn bright_red(&self) {
+        self.set_fill_color_rgb(255, 0, 0)
+    }
+
+    pub fn dark_green(&self) {
+        self.set_fill_color_rgb(0, 80, 0)
+    }
+
+    pub fn bright_green(&self) {
+        self.set_fill_color_rgb(0, 255, 0)
+    }
+
+    /// Draw a single character in the box at `x`, `y`.
+    pub fn draw_byte(&self, x: usize, y: usize, byte: u8) {
+        unsafe { sys::draw_byte(x, y, byte) }
+    }
+
+    pub fn fill_box(&self, x: usize, y: usize) {
+        unsafe { sys::fill_box(x, y) }
+    }
+
+    pub fn clear(&self) {
+        unsafe { sys::clear() }
+    }
+}
+
+/// Which square of a puzzle's grid is on screen.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Viewport {
+    top_left: Point,
+}
+
+impl Viewport {
+    pub fn new(top_left: Point) -> Self {
+        Self { top_left }
+    }
+
+    /// The view with `focus` in the middle, for puzzles on an unbounded plane.
+    pub fn around(focus: Point) -> Self {
+        let half = (Canvas::SIZE / 2) as i32;
+        Self::new(Point::new(focus.x - half, focus.y - half))
+    }
+
+    /// The view with `focus` as close to the middle as it can be without showing anything outside
+    /// `world`. A world smaller than the screen is drawn from the top left.
+    pub fn within(focus: Point, world: Bounds) -> Self {
+        let size = Canvas::SIZE as i32;
+        let axis = |focus: i32, min: i32, max: i32| (focus - size / 2).min(max - size + 1).max(min);
+        Self::new(Point::new(
+            axis(focus.x, world.min.x, world.max.x),
+            axis(focus.y, world.min.y, world.max.y),
+        ))
+    }
+
+    /// The box that `point` is drawn in, or `None` if it's off screen.
+    pub fn to_screen(&self, point: Point) -> Option<(usize, usize)> {
+        let x = usize::try_from(point.x - self.top_left.x).ok()?;
+        let y = usize::try_from(point.y - self.top_left.y).ok()?;
+        (x < Canvas::SIZE && y < Canvas::SIZE).then_some((x, y))
+    }
+}
+
+/// Write a message to the browser's console.
+pub fn log(text: impl AsRef<str>) {
+    let text = text.as_ref();
+    unsafe { sys::console_log(text.as_ptr(), text.len() as u32) }
+}
+
+/// The drawing functions the page gives the module.
+#[cfg(target_arch = "wasm32")]
+mod sys {
+    extern "C" {
+        /// Draw a single byte to the screen, in a box
+        pub fn draw_byte(x: usize, y: usize, byte: u8);
+        pub fn fill_box(x: usize, y: usize);
+        pub fn clear();
+        pub fn fill_color_rgb(r: u8, g: u8, b: u8);
+        pub fn console_log(ptr: *const u8, len: u32);
+    }
+}
+
+/// Outside a browser there's nowhere to draw, so drawing does nothing. This lets the animations be
+/// built and tested like the rest of the workspace.
+#[cfg(not(target_arch = "wasm32"))]
+#[allow(clippy::missing_safety_doc)]
+mod sys {
+    pub unsafe fn draw_byte(_x: usize, _y: usize, _byte: u8) {}
+    pub unsafe fn fill_box(_x: usize, _y: usize) {}
+    pub unsafe fn clear() {}
+    pub unsafe fn fill_color_rgb(_r: u8, _g: u8, _b: u8) {}
+    pub unsafe fn console_log(_ptr: *const u8, _len: u32) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_viewport_within() {
+        let world = Bounds {
+            min: Point::new(0, 0),
+            max: Point::new(99, 9),
+        };
+        let viewport = Viewport::within(Point::new(50, 5), world);
+        assert_eq!(viewport, Viewport::new(Point::new(18, 0)));
+        assert_eq!(viewport.to_screen(Point::new(50, 5)), Some((32, 5)));
+
+        let viewport = Viewport::within(Point::new(95, 5), world);
+        assert_eq!(viewport.to_screen(Point::new(99, 9)), Some((63, 9)));
+        assert_eq!(viewport.to_screen(Point::new(35, 0)), None);
+    }
+
+    #[test]
+    fn test_viewport_around() {
+        let viewport = Viewport::around(Point::new(-10, 3));
+        assert_eq!(viewport.to_screen(Point::new(-10, 3)), Some((32, 32)));
+        assert_eq!(viewport.to_screen(Point::new(-43, 3)), None);
+    }
+}